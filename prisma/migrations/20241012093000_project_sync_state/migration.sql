@@ -0,0 +1,40 @@
+-- CreateTable
+CREATE TABLE "ProjectSync" (
+    "id" SERIAL NOT NULL,
+    "projectId" INTEGER NOT NULL,
+    "lastRunAt" TIMESTAMP(3),
+    "lastSuccessAt" TIMESTAMP(3),
+    "lastError" TEXT,
+    "lastErrorAt" TIMESTAMP(3),
+    "consecutiveFailures" INTEGER NOT NULL DEFAULT 0,
+    "itemsCreated" INTEGER NOT NULL DEFAULT 0,
+    "itemsUpdated" INTEGER NOT NULL DEFAULT 0,
+    "durationMs" INTEGER NOT NULL DEFAULT 0,
+    "requestedAt" TIMESTAMP(3),
+
+    CONSTRAINT "ProjectSync_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "SyncRun" (
+    "id" SERIAL NOT NULL,
+    "projectId" INTEGER NOT NULL,
+    "startedAt" TIMESTAMP(3) NOT NULL,
+    "finishedAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "success" BOOLEAN NOT NULL,
+    "error" TEXT,
+    "itemsCreated" INTEGER NOT NULL,
+    "itemsUpdated" INTEGER NOT NULL,
+    "durationMs" INTEGER NOT NULL,
+
+    CONSTRAINT "SyncRun_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ProjectSync_projectId_key" ON "ProjectSync"("projectId");
+
+-- AddForeignKey
+ALTER TABLE "ProjectSync" ADD CONSTRAINT "ProjectSync_projectId_fkey" FOREIGN KEY ("projectId") REFERENCES "Project"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "SyncRun" ADD CONSTRAINT "SyncRun_projectId_fkey" FOREIGN KEY ("projectId") REFERENCES "Project"("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,4 @@
+-- AlterTable
+ALTER TABLE "Project" ADD COLUMN     "repoBaseUrl" TEXT,
+ADD COLUMN     "repoProvider" TEXT NOT NULL DEFAULT 'github',
+ADD COLUMN     "repoToken" TEXT;
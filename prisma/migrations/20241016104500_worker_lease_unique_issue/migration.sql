@@ -0,0 +1,31 @@
+-- Move assignees of duplicate issue tasks onto the oldest task of each group
+INSERT INTO "_AssignedTask" ("A", "B")
+SELECT DISTINCT keeper."id", assigned."B"
+FROM "Task" duplicate
+JOIN "Task" keeper
+    ON keeper."projectId" = duplicate."projectId"
+    AND keeper."assignedIssue" = duplicate."assignedIssue"
+    AND keeper."id" < duplicate."id"
+JOIN "_AssignedTask" assigned ON assigned."A" = duplicate."id"
+WHERE duplicate."assignedIssue" IS NOT NULL
+ON CONFLICT DO NOTHING;
+
+-- Remove duplicate issue tasks, keeping the oldest one
+DELETE FROM "Task" duplicate
+USING "Task" keeper
+WHERE duplicate."assignedIssue" IS NOT NULL
+    AND keeper."projectId" = duplicate."projectId"
+    AND keeper."assignedIssue" = duplicate."assignedIssue"
+    AND keeper."id" < duplicate."id";
+
+-- CreateTable
+CREATE TABLE "WorkerLease" (
+    "name" TEXT NOT NULL,
+    "holder" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+
+    CONSTRAINT "WorkerLease_pkey" PRIMARY KEY ("name")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Task_projectId_assignedIssue_key" ON "Task"("projectId", "assignedIssue");
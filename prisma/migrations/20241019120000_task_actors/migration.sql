@@ -0,0 +1,11 @@
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN     "createdById" INTEGER,
+ADD COLUMN     "createdBySystem" TEXT,
+ADD COLUMN     "updatedById" INTEGER,
+ADD COLUMN     "updatedBySystem" TEXT;
+
+-- AddForeignKey
+ALTER TABLE "Task" ADD CONSTRAINT "Task_createdById_fkey" FOREIGN KEY ("createdById") REFERENCES "User"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Task" ADD CONSTRAINT "Task_updatedById_fkey" FOREIGN KEY ("updatedById") REFERENCES "User"("id") ON DELETE SET NULL ON UPDATE CASCADE;
@@ -0,0 +1,24 @@
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN     "commentsSyncedAt" TIMESTAMP(3);
+
+-- CreateTable
+CREATE TABLE "ExternalComment" (
+    "id" SERIAL NOT NULL,
+    "taskId" INTEGER NOT NULL,
+    "externalId" TEXT NOT NULL,
+    "author" TEXT NOT NULL,
+    "authorUrl" TEXT,
+    "body" TEXT NOT NULL,
+    "url" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    "syncedAt" TIMESTAMP(3) NOT NULL,
+
+    CONSTRAINT "ExternalComment_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ExternalComment_taskId_externalId_key" ON "ExternalComment"("taskId", "externalId");
+
+-- AddForeignKey
+ALTER TABLE "ExternalComment" ADD CONSTRAINT "ExternalComment_taskId_fkey" FOREIGN KEY ("taskId") REFERENCES "Task"("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,9 @@
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN     "parentId" INTEGER,
+ADD COLUMN     "position" INTEGER NOT NULL DEFAULT 0;
+
+-- CreateIndex
+CREATE INDEX "Task_parentId_position_idx" ON "Task"("parentId", "position");
+
+-- AddForeignKey
+ALTER TABLE "Task" ADD CONSTRAINT "Task_parentId_fkey" FOREIGN KEY ("parentId") REFERENCES "Task"("id") ON DELETE SET NULL ON UPDATE CASCADE;
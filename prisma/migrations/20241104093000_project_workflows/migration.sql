@@ -0,0 +1,61 @@
+-- CreateTable
+CREATE TABLE "ProjectStatus" (
+    "id" SERIAL NOT NULL,
+    "projectId" INTEGER NOT NULL,
+    "key" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "category" TEXT NOT NULL,
+    "position" INTEGER NOT NULL,
+
+    CONSTRAINT "ProjectStatus_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "StatusTransition" (
+    "id" SERIAL NOT NULL,
+    "fromId" INTEGER NOT NULL,
+    "toId" INTEGER NOT NULL,
+
+    CONSTRAINT "StatusTransition_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ProjectStatus_projectId_key_key" ON "ProjectStatus"("projectId", "key");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "StatusTransition_fromId_toId_key" ON "StatusTransition"("fromId", "toId");
+
+-- AddForeignKey
+ALTER TABLE "ProjectStatus" ADD CONSTRAINT "ProjectStatus_projectId_fkey" FOREIGN KEY ("projectId") REFERENCES "Project"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "StatusTransition" ADD CONSTRAINT "StatusTransition_fromId_fkey" FOREIGN KEY ("fromId") REFERENCES "ProjectStatus"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "StatusTransition" ADD CONSTRAINT "StatusTransition_toId_fkey" FOREIGN KEY ("toId") REFERENCES "ProjectStatus"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- Give every existing project the statuses of the former fixed workflow
+INSERT INTO "ProjectStatus" ("projectId", "key", "name", "category", "position")
+SELECT p."id", s."key", s."name", s."category", s."position"
+FROM "Project" p
+CROSS JOIN (VALUES
+    ('todo', 'Todo', 'todo', 0),
+    ('in_progress', 'In progress', 'in_progress', 1),
+    ('in_review', 'In review', 'in_progress', 2),
+    ('blocked', 'Blocked', 'in_progress', 3),
+    ('done', 'Done', 'done', 4),
+    ('cancelled', 'Cancelled', 'cancelled', 5)
+) AS s("key", "name", "category", "position");
+
+-- Keep any other value found in tasks as a status of its project, so no task is left without one
+INSERT INTO "ProjectStatus" ("projectId", "key", "name", "category", "position")
+SELECT u."projectId", u."status", u."status", 'todo',
+    CAST(5 + ROW_NUMBER() OVER (PARTITION BY u."projectId" ORDER BY u."status") AS INTEGER)
+FROM (
+    SELECT "projectId", "status" FROM "Task"
+    UNION
+    SELECT "projectId", "statusBeforeBlock" FROM "Task" WHERE "statusBeforeBlock" IS NOT NULL
+) u
+WHERE NOT EXISTS (
+    SELECT 1 FROM "ProjectStatus" s WHERE s."projectId" = u."projectId" AND s."key" = u."status"
+);
@@ -0,0 +1,14 @@
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN     "boardPosition" DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+-- Spread existing tasks over their columns in the order they used to be listed
+UPDATE "Task" t
+SET "boardPosition" = r."rank" * 1024
+FROM (
+    SELECT "id", ROW_NUMBER() OVER (PARTITION BY "projectId", "status" ORDER BY "due_date" ASC NULLS LAST, "id") AS "rank"
+    FROM "Task"
+) r
+WHERE t."id" = r."id";
+
+-- CreateIndex
+CREATE INDEX "Task_projectId_status_boardPosition_idx" ON "Task"("projectId", "status", "boardPosition");
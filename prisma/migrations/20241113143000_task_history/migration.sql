@@ -0,0 +1,22 @@
+-- CreateTable
+CREATE TABLE "TaskChange" (
+    "id" SERIAL NOT NULL,
+    "taskId" INTEGER NOT NULL,
+    "field" TEXT NOT NULL,
+    "oldValue" JSONB,
+    "newValue" JSONB,
+    "changedById" INTEGER,
+    "changedBySystem" TEXT,
+    "changedAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "TaskChange_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE INDEX "TaskChange_taskId_changedAt_idx" ON "TaskChange"("taskId", "changedAt");
+
+-- AddForeignKey
+ALTER TABLE "TaskChange" ADD CONSTRAINT "TaskChange_taskId_fkey" FOREIGN KEY ("taskId") REFERENCES "Task"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TaskChange" ADD CONSTRAINT "TaskChange_changedById_fkey" FOREIGN KEY ("changedById") REFERENCES "User"("id") ON DELETE SET NULL ON UPDATE CASCADE;
@@ -0,0 +1,28 @@
+-- The russian configuration stems Cyrillic words with the Russian snowball stemmer
+-- and Latin words with the English one, so a single configuration covers both languages.
+
+-- AlterTable
+ALTER TABLE "Project" ADD COLUMN "searchVector" tsvector GENERATED ALWAYS AS (
+    setweight(to_tsvector('russian', coalesce("name", '')), 'A') ||
+    setweight(to_tsvector('russian', coalesce("description", '')), 'B')
+) STORED;
+
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN "searchVector" tsvector GENERATED ALWAYS AS (
+    setweight(to_tsvector('russian', coalesce("name", '')), 'A') ||
+    setweight(to_tsvector('russian', coalesce("description", '')), 'B')
+) STORED;
+
+-- AlterTable
+ALTER TABLE "Comment" ADD COLUMN "searchVector" tsvector GENERATED ALWAYS AS (
+    to_tsvector('russian', coalesce("body", ''))
+) STORED;
+
+-- CreateIndex
+CREATE INDEX "Project_searchVector_idx" ON "Project" USING GIN ("searchVector");
+
+-- CreateIndex
+CREATE INDEX "Task_searchVector_idx" ON "Task" USING GIN ("searchVector");
+
+-- CreateIndex
+CREATE INDEX "Comment_searchVector_idx" ON "Comment" USING GIN ("searchVector");
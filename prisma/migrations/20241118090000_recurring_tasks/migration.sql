@@ -0,0 +1,21 @@
+-- AlterTable
+ALTER TABLE "Task" ADD COLUMN     "nextCreated" BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN     "recurrenceId" INTEGER;
+
+-- CreateTable
+CREATE TABLE "TaskRecurrence" (
+    "id" SERIAL NOT NULL,
+    "projectId" INTEGER NOT NULL,
+    "rule" TEXT NOT NULL,
+    "until" TIMESTAMP(3),
+    "active" BOOLEAN NOT NULL DEFAULT true,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "TaskRecurrence_pkey" PRIMARY KEY ("id")
+);
+
+-- AddForeignKey
+ALTER TABLE "Task" ADD CONSTRAINT "Task_recurrenceId_fkey" FOREIGN KEY ("recurrenceId") REFERENCES "TaskRecurrence"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TaskRecurrence" ADD CONSTRAINT "TaskRecurrence_projectId_fkey" FOREIGN KEY ("projectId") REFERENCES "Project"("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,58 @@
+-- CreateTable
+CREATE TABLE "ProjectTemplate" (
+    "id" SERIAL NOT NULL,
+    "ownerId" INTEGER NOT NULL,
+    "name" TEXT NOT NULL,
+    "description" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "ProjectTemplate_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "TaskTemplate" (
+    "id" SERIAL NOT NULL,
+    "ownerId" INTEGER NOT NULL,
+    "projectTemplateId" INTEGER,
+    "name" TEXT NOT NULL,
+    "description" TEXT NOT NULL,
+    "priority" TEXT NOT NULL DEFAULT 'none',
+    "estimate" DOUBLE PRECISION,
+    "assigneeRoles" TEXT[],
+    "dueOffsetDays" INTEGER,
+    "position" INTEGER NOT NULL DEFAULT 0,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "TaskTemplate_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "TemplateRole" (
+    "id" SERIAL NOT NULL,
+    "projectTemplateId" INTEGER NOT NULL,
+    "name" TEXT NOT NULL,
+    "defaultUserId" INTEGER,
+
+    CONSTRAINT "TemplateRole_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE INDEX "TaskTemplate_projectTemplateId_position_idx" ON "TaskTemplate"("projectTemplateId", "position");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "TemplateRole_projectTemplateId_name_key" ON "TemplateRole"("projectTemplateId", "name");
+
+-- AddForeignKey
+ALTER TABLE "ProjectTemplate" ADD CONSTRAINT "ProjectTemplate_ownerId_fkey" FOREIGN KEY ("ownerId") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TaskTemplate" ADD CONSTRAINT "TaskTemplate_ownerId_fkey" FOREIGN KEY ("ownerId") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TaskTemplate" ADD CONSTRAINT "TaskTemplate_projectTemplateId_fkey" FOREIGN KEY ("projectTemplateId") REFERENCES "ProjectTemplate"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TemplateRole" ADD CONSTRAINT "TemplateRole_projectTemplateId_fkey" FOREIGN KEY ("projectTemplateId") REFERENCES "ProjectTemplate"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "TemplateRole" ADD CONSTRAINT "TemplateRole_defaultUserId_fkey" FOREIGN KEY ("defaultUserId") REFERENCES "User"("id") ON DELETE SET NULL ON UPDATE CASCADE;
@@ -0,0 +1,44 @@
+-- CreateTable
+CREATE TABLE "_TaskWatchers" (
+    "A" INTEGER NOT NULL,
+    "B" INTEGER NOT NULL
+);
+
+-- CreateTable
+CREATE TABLE "_ProjectWatchers" (
+    "A" INTEGER NOT NULL,
+    "B" INTEGER NOT NULL
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "_TaskWatchers_AB_unique" ON "_TaskWatchers"("A", "B");
+
+-- CreateIndex
+CREATE INDEX "_TaskWatchers_B_index" ON "_TaskWatchers"("B");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "_ProjectWatchers_AB_unique" ON "_ProjectWatchers"("A", "B");
+
+-- CreateIndex
+CREATE INDEX "_ProjectWatchers_B_index" ON "_ProjectWatchers"("B");
+
+-- AddForeignKey
+ALTER TABLE "_TaskWatchers" ADD CONSTRAINT "_TaskWatchers_A_fkey" FOREIGN KEY ("A") REFERENCES "Task"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "_TaskWatchers" ADD CONSTRAINT "_TaskWatchers_B_fkey" FOREIGN KEY ("B") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "_ProjectWatchers" ADD CONSTRAINT "_ProjectWatchers_A_fkey" FOREIGN KEY ("A") REFERENCES "Project"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "_ProjectWatchers" ADD CONSTRAINT "_ProjectWatchers_B_fkey" FOREIGN KEY ("B") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- Existing creators, assignees and commenters watch their tasks
+INSERT INTO "_TaskWatchers" ("A", "B")
+SELECT "id", "createdById" FROM "Task" WHERE "createdById" IS NOT NULL
+UNION
+SELECT "A", "B" FROM "_AssignedTask"
+UNION
+SELECT "taskId", "authorId" FROM "Comment"
+ON CONFLICT DO NOTHING;
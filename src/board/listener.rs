@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use reqwest::Url;
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Duration};
+use tokio_postgres::{AsyncMessage, NoTls};
+use tokio_util::sync::CancellationToken;
+
+use crate::models::board::BoardEvent;
+use crate::services::board::BOARD_EVENTS_CHANNEL;
+
+const LOG_TAG: &'static str = "BoardEventListener";
+/// How long to wait before connecting again after the connection was lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Forwards the board events published by any replica to the viewers connected to this one.
+pub struct BoardEventListener {
+    database_url: String,
+    events: broadcast::Sender<BoardEvent>,
+    cancel_token: CancellationToken,
+}
+
+impl BoardEventListener {
+    pub fn new(database_url: &str, events: broadcast::Sender<BoardEvent>, cancel_token: CancellationToken) -> Self {
+        Self {
+            database_url: database_url.to_string(),
+            events,
+            cancel_token,
+        }
+    }
+
+    pub async fn work(&self) -> Result<()> {
+        log::info!(target: LOG_TAG, "Board event listener started");
+        loop {
+            tokio::select! {
+                result = self.listen() => {
+                    if let Err(e) = result {
+                        log::error!(target: LOG_TAG, "Board event connection failed: {:#}", e);
+                    }
+                }
+                _ = self.cancel_token.cancelled() => break,
+            }
+            tokio::select! {
+                _ = sleep(RECONNECT_DELAY) => {}
+                _ = self.cancel_token.cancelled() => break,
+            }
+        }
+        log::info!(target: LOG_TAG, "Graceful shutdown triggered");
+        Ok(())
+    }
+
+    /// Connection settings from the Prisma database url, whose pool options Postgres doesn't know.
+    fn connection_config(&self) -> Result<tokio_postgres::Config> {
+        let url = Url::parse(&self.database_url).context("Invalid DATABASE_URL")?;
+        let mut config = tokio_postgres::Config::new();
+        config
+            .host(url.host_str().context("DATABASE_URL has no host")?)
+            .port(url.port().unwrap_or(5432))
+            .user(&urlencoding::decode(url.username()).context("Invalid DATABASE_URL user")?)
+            .dbname(url.path().trim_start_matches('/'));
+        if let Some(password) = url.password() {
+            config.password(urlencoding::decode(password).context("Invalid DATABASE_URL password")?.as_bytes());
+        }
+        Ok(config)
+    }
+
+    async fn listen(&self) -> Result<()> {
+        let (client, mut connection) = self.connection_config()?
+            .connect(NoTls)
+            .await
+            .context("Failed to connect")?;
+
+        // The connection only delivers notifications while it is polled
+        let (notifications, mut received) = tokio::sync::mpsc::unbounded_channel();
+        let connection = tokio::spawn(async move {
+            while let Some(message) = std::future::poll_fn(|cx| connection.poll_message(cx)).await {
+                match message {
+                    Ok(AsyncMessage::Notification(notification)) => {
+                        if notifications.send(notification.payload().to_string()).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        });
+
+        client
+            .batch_execute(&format!("LISTEN {}", BOARD_EVENTS_CHANNEL))
+            .await
+            .context("Failed to listen for board events")?;
+        log::info!(target: LOG_TAG, "Listening for board events");
+
+        while let Some(payload) = received.recv().await {
+            match serde_json::from_str::<BoardEvent>(&payload) {
+                // Nobody may be watching the board, which is not an error
+                Ok(event) => { let _ = self.events.send(event); }
+                Err(e) => log::error!(target: LOG_TAG, "Malformed board event {}: {}", payload, e),
+            }
+        }
+        connection.await.context("Connection task failed")?.context("Connection lost")?;
+        anyhow::bail!("Connection closed")
+    }
+}
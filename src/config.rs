@@ -1,16 +1,54 @@
 use std::env;
+use std::str::FromStr;
 
 use dotenvy::dotenv;
 
+use crate::utils::secret::SecretKey;
+
+/// How much space task attachments may take.
+#[derive(Clone)]
+pub struct AttachmentSettings {
+    pub max_file_size: u64,
+    pub project_quota: u64,
+}
+
+/// Backend that keeps uploaded files.
+#[derive(Clone)]
+pub enum StorageSettings {
+    Local {
+        root: String,
+    },
+    S3 {
+        bucket: String,
+        region: String,
+        endpoint: Option<String>,
+        access_key: String,
+        secret_key: String,
+        path_style: bool,
+    },
+}
+
+/// Access to the repositories linked to projects.
+#[derive(Clone)]
+pub struct RepositorySettings {
+    /// Encrypts the access tokens stored in the database
+    pub token_key: SecretKey,
+    /// Hosts allowed as repository base urls even though they resolve to private addresses
+    pub allowed_hosts: Vec<String>,
+}
+
 pub struct Config {
     pub unisender_api_key: String,
     pub unisender_sender_name: String,
     pub unisender_sender_email: String,
     pub github_app_id: u64,
     pub github_app_private_key: String,
+    pub attachments: AttachmentSettings,
+    pub storage: StorageSettings,
+    pub storage_signing_secret: String,
+    pub repositories: RepositorySettings,
     #[allow(unused)]
     pub jwt_secret: String,
-    #[allow(unused)]
     pub database_url: String
 }
 
@@ -29,6 +67,19 @@ impl Config {
         })
     }
 
+    pub fn get_env_param_or<T: FromStr>(param_name: &str, default: T) -> T
+    where
+        T::Err: std::fmt::Display,
+    {
+        match env::var(param_name) {
+            Ok(value) => value.parse().unwrap_or_else(|err| {
+                log::error!("Failed to parse {param_name}: {err}");
+                std::process::exit(1);
+            }),
+            Err(_) => default,
+        }
+    }
+
     pub fn from_env() -> Config {
         if Self::is_debug() {
             dotenv().ok();
@@ -47,6 +98,37 @@ impl Config {
             }
         };
         let github_app_private_key = Self::get_env_param("GITHUB_APP_PRIVATE_KEY");
+        let attachments = AttachmentSettings {
+            max_file_size: Self::get_env_param_or("ATTACHMENT_MAX_FILE_SIZE", 25 * 1024 * 1024),
+            project_quota: Self::get_env_param_or("ATTACHMENT_PROJECT_QUOTA", 1024 * 1024 * 1024),
+        };
+        let storage = match env::var("STORAGE_BACKEND").unwrap_or("local".to_string()).as_str() {
+            "local" => StorageSettings::Local {
+                root: env::var("STORAGE_LOCAL_ROOT").unwrap_or("/uploads".to_string()),
+            },
+            "s3" => StorageSettings::S3 {
+                bucket: Self::get_env_param("S3_BUCKET"),
+                region: env::var("S3_REGION").unwrap_or("us-east-1".to_string()),
+                endpoint: env::var("S3_ENDPOINT").ok(),
+                access_key: Self::get_env_param("S3_ACCESS_KEY"),
+                secret_key: Self::get_env_param("S3_SECRET_KEY"),
+                path_style: Self::get_env_param_or("S3_PATH_STYLE", true),
+            },
+            backend => {
+                log::error!("Unknown STORAGE_BACKEND {backend}, expected local or s3");
+                std::process::exit(1);
+            }
+        };
+        let storage_signing_secret = Self::get_env_param("STORAGE_SIGNING_SECRET");
+        let repositories = RepositorySettings {
+            token_key: SecretKey::new(&Self::get_env_param("REPOSITORY_TOKEN_KEY")),
+            allowed_hosts: env::var("REPOSITORY_ALLOWED_HOSTS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|host| host.trim().to_lowercase())
+                .filter(|host| !host.is_empty())
+                .collect(),
+        };
         let jwt_secret = Self::get_env_param("JWT_SECRET");
         let database_url = Self::get_env_param("DATABASE_URL");
 
@@ -56,6 +138,10 @@ impl Config {
             unisender_sender_email,
             github_app_id,
             github_app_private_key,
+            attachments,
+            storage,
+            storage_signing_secret,
+            repositories,
             jwt_secret,
             database_url
         }
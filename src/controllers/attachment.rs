@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use actix_multipart::form::MultipartForm;
+use actix_web::{
+    http::header::{self, ContentDisposition, DispositionParam, DispositionType},
+    web::{Data, Json, Path, ReqData},
+    HttpResponse,
+};
+use apistos::api_operation;
+
+use crate::{
+    models::attachment::{SelectAttachment, UploadAttachment},
+    services::attachment::{create_attachment, get_attachment_file, get_task_attachments, AttachmentUploadError},
+    storage::ResponseOverrides,
+    utils::{app_data::AppData, response::{ErrorResponse, SuccessResponse}}
+};
+
+/// Downloads are redirected to a storage URL that stays valid this long.
+const DOWNLOAD_URL_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[api_operation(
+    summary = "Get task attachments",
+    description = "Get the files attached to a task",
+    tag = "Tasks",
+    error_code = "401"
+)]
+pub async fn get_attachments(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectAttachment>>>, ErrorResponse> {
+    let attachments = get_task_attachments(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(attachments)))
+}
+
+#[api_operation(
+    summary = "Delete attachment",
+    description = "Delete a file attached to a task. Allowed to its uploader and the project owner",
+    tag = "Tasks",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_attachment(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    path: Path<(u64, u64)>
+) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    crate::services::attachment::delete_attachment(app_data.storage.as_ref(), *user_id, path.0, path.1).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Attachment not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+pub async fn upload_attachment(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    body: MultipartForm<UploadAttachment>,
+) -> Result<Json<SuccessResponse<SelectAttachment>>, ErrorResponse> {
+    let file = &body.file;
+    let name = file.file_name.clone().unwrap_or_else(|| "file".to_string());
+    let attachment = create_attachment(app_data.storage.as_ref(), &app_data.attachments, *user_id, *task_id, &name, file.file.path()).await
+        .map_err(|e| match e {
+            AttachmentUploadError::TooLarge(reason) => ErrorResponse::BadRequest(reason),
+            AttachmentUploadError::Failed(err) => ErrorResponse::InternalServerError(err),
+        })?;
+
+    Ok(Json(SuccessResponse::new(attachment)))
+}
+
+pub async fn download_attachment(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    attachment_id: Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let (attachment, storage_key) = get_attachment_file(*user_id, *attachment_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Attachment not found".to_string()))?;
+
+    let overrides = ResponseOverrides {
+        content_type: Some(attachment.mime_type),
+        content_disposition: Some(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(attachment.name)],
+        }.to_string()),
+    };
+    let url = app_data.storage.presigned_url(&storage_key, DOWNLOAD_URL_TTL, &overrides).await
+        .map_err(|e| ErrorResponse::InternalServerError(format!("{:#}", e)))?;
+
+    Ok(HttpResponse::Found().insert_header((header::LOCATION, url)).finish())
+}
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use actix_web::web::{Data, Json, Path, Query, ReqData};
+use apistos::api_operation;
+use garde::Validate;
+
+use crate::{
+    models::comment::{CreateCommentRequest, SelectComment, SelectCommentPage, SelectCommentsRequest, UpdateCommentRequest},
+    services::{comment::get_task_comments, notifications::create_notification, task::get_task_by_id},
+    utils::{app_data::AppData, response::{ErrorResponse, SuccessResponse}}
+};
+
+async fn notify_mentions(app_data: &AppData, comment: &SelectComment, mentions: &HashSet<u64>) {
+    if mentions.is_empty() {
+        return;
+    }
+    let task_name = match get_task_by_id(comment.task_id).await {
+        Ok(Some(task)) => task.name,
+        _ => return,
+    };
+    let author = format!("{} {}", comment.author.first_name, comment.author.last_name);
+    for user_id in mentions {
+        create_notification(
+            "Вас упомянули в комментарии".to_string(),
+            format!("{} упомянул вас в комментарии к задаче {}: {}", author, task_name, comment.body),
+            *user_id,
+            &app_data.mailer
+        ).await;
+    }
+}
+
+#[api_operation(
+    summary = "Get task comments",
+    description = "Get a page of task comments, oldest first",
+    tag = "Comments",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn get_comments(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    filters: Query<SelectCommentsRequest>
+) -> Result<Json<SuccessResponse<SelectCommentPage>>, ErrorResponse> {
+    filters.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let comments = get_task_comments(*user_id, *task_id, &*filters).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(comments)))
+}
+
+#[api_operation(
+    summary = "Create comment",
+    description = "Comment on a task. Mentioned project participants are notified",
+    tag = "Comments",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_comment(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    comment: Json<CreateCommentRequest>
+) -> Result<Json<SuccessResponse<SelectComment>>, ErrorResponse> {
+    comment.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let (comment, mentions) = crate::services::comment::create_comment(*user_id, *task_id, &*comment).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+    notify_mentions(&app_data, &comment, &mentions).await;
+
+    Ok(Json(SuccessResponse::new(comment)))
+}
+
+#[api_operation(
+    summary = "Update comment",
+    description = "Edit a comment. Only the author can edit it",
+    tag = "Comments",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn update_comment(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    path: Path<(u64, u64)>,
+    comment: Json<UpdateCommentRequest>
+) -> Result<Json<SuccessResponse<SelectComment>>, ErrorResponse> {
+    comment.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let (comment, mentions) = crate::services::comment::update_comment(*user_id, path.0, path.1, &*comment).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Comment not found".to_string()))?;
+    notify_mentions(&app_data, &comment, &mentions).await;
+
+    Ok(Json(SuccessResponse::new(comment)))
+}
+
+#[api_operation(
+    summary = "Delete comment",
+    description = "Delete a comment. Only the author can delete it",
+    tag = "Comments",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_comment(user_id: ReqData<u64>, path: Path<(u64, u64)>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    crate::services::comment::delete_comment(*user_id, path.0, path.1).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Comment not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
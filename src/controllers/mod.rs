@@ -7,6 +7,12 @@ pub mod auth;
 pub mod project;
 pub mod task;
 pub mod notification;
+pub mod comment;
+pub mod attachment;
+pub mod storage;
+pub mod time_entry;
+pub mod search;
+pub mod template;
 
 pub fn init_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -20,6 +26,7 @@ pub fn init_routes(cfg: &mut web::ServiceConfig) {
             .wrap(Authentication)
             .route("/", web::get().to(user::get_all))
             .route("/me", web::get().to(user::get_me))
+            .route("/me/avatar", web::delete().to(user::delete_avatar))
     );
     cfg.service(
         web::scope("/projects")
@@ -31,23 +38,86 @@ pub fn init_routes(cfg: &mut web::ServiceConfig) {
             .route("/{project_id}", web::delete().to(project::delete_project))
             .route("/{project_id}/members/{member_id}", web::post().to(project::add_member))
             .route("/{project_id}/members/{member_id}", web::delete().to(project::remove_member))
+            .route("/{project_id}/sync", web::get().to(project::get_sync))
+            .route("/{project_id}/sync", web::post().to(project::trigger_sync))
+            .route("/{project_id}/workflow", web::get().to(project::get_project_workflow))
+            .route("/{project_id}/workflow", web::put().to(project::update_project_workflow))
+            .route("/{project_id}/board", web::get().to(project::get_project_board))
+            .route("/{project_id}/labels", web::get().to(project::get_labels))
+            .route("/{project_id}/labels", web::post().to(project::create_project_label))
+            .route("/{project_id}/labels/{label_id}", web::patch().to(project::update_project_label))
+            .route("/{project_id}/labels/{label_id}", web::delete().to(project::delete_project_label))
+            .route("/{project_id}/time-report", web::get().to(time_entry::get_project_time_report))
+            .route("/{project_id}/template", web::post().to(template::save_project_template))
+            .route("/{project_id}/watch", web::post().to(project::watch_project))
+            .route("/{project_id}/watch", web::delete().to(project::unwatch_project))
     );
     cfg.service(
         web::scope("/tasks")
             .wrap(Authentication)
+            .route("", web::get().to(task::get_list))
             .route("/my", web::get().to(task::get_my))
             .route("/{task_id}", web::get().to(task::get_by_id))
             .route("/", web::post().to(task::create_task))
+            .route("/bulk", web::post().to(task::bulk_update))
             .route("/{task_id}", web::patch().to(task::update_task))
             .route("/{task_id}", web::delete().to(task::delete_task))
             .route("/{task_id}/assignees/{assignee_id}", web::post().to(task::add_assignee))
             .route("/{task_id}/assignees/{assignee_id}", web::delete().to(task::remove_assignee))
+            .route("/{task_id}/subtasks", web::get().to(task::get_subtasks_of))
+            .route("/{task_id}/subtasks", web::post().to(task::create_subtask))
+            .route("/{task_id}/subtasks/order", web::put().to(task::reorder_subtasks))
+            .route("/{task_id}/move", web::post().to(task::move_task))
+            .route("/{task_id}/move-to-project", web::post().to(task::move_to_project))
+            .route("/{task_id}/duplicate", web::post().to(task::duplicate))
+            .route("/{task_id}/watchers", web::get().to(task::get_watchers))
+            .route("/{task_id}/watch", web::post().to(task::watch))
+            .route("/{task_id}/watch", web::delete().to(task::unwatch))
+            .route("/{task_id}/dependencies", web::get().to(task::get_dependencies))
+            .route("/{task_id}/dependencies", web::post().to(task::create_dependency))
+            .route("/{task_id}/dependencies/{blocker_id}", web::delete().to(task::delete_dependency))
+            .route("/{task_id}/history", web::get().to(task::get_history))
+            .route("/{task_id}/history/{change_id}/revert", web::post().to(task::revert_change))
+            .route("/{task_id}/recurrence", web::get().to(task::get_recurrence))
+            .route("/{task_id}/recurrence", web::put().to(task::set_recurrence))
+            .route("/{task_id}/recurrence", web::delete().to(task::stop_recurrence))
+            .route("/{task_id}/discussion", web::get().to(task::get_discussion))
+            .route("/{task_id}/discussion", web::post().to(task::reply_to_discussion))
+            .route("/{task_id}/comments", web::get().to(comment::get_comments))
+            .route("/{task_id}/comments", web::post().to(comment::create_comment))
+            .route("/{task_id}/comments/{comment_id}", web::patch().to(comment::update_comment))
+            .route("/{task_id}/comments/{comment_id}", web::delete().to(comment::delete_comment))
+            .route("/{task_id}/attachments", web::get().to(attachment::get_attachments))
+            .route("/{task_id}/attachments/{attachment_id}", web::delete().to(attachment::delete_attachment))
+            .route("/{task_id}/timer", web::post().to(time_entry::start_task_timer))
+            .route("/{task_id}/timer", web::delete().to(time_entry::stop_task_timer))
+            .route("/{task_id}/time-entries", web::get().to(time_entry::get_time_entries))
+            .route("/{task_id}/time-entries", web::post().to(time_entry::create_task_worklog))
+            .route("/{task_id}/time-entries/{entry_id}", web::delete().to(time_entry::delete_task_time_entry))
     );
     cfg.service(
         web::scope("/notifications")
             .wrap(Authentication)
             .route("/my", web::get().to(notification::get_my))
     );
+    cfg.service(
+        web::scope("/search")
+            .wrap(Authentication)
+            .route("/", web::get().to(search::search))
+    );
+    cfg.service(
+        web::scope("/templates")
+            .wrap(Authentication)
+            .route("/tasks", web::get().to(template::get_task_templates_list))
+            .route("/tasks", web::post().to(template::create_task_template_entry))
+            .route("/tasks/{template_id}", web::delete().to(template::delete_task_template_entry))
+            .route("/tasks/{template_id}/apply", web::post().to(template::apply_task_template))
+            .route("/projects", web::get().to(template::get_project_templates_list))
+            .route("/projects", web::post().to(template::create_project_template_entry))
+            .route("/projects/{template_id}", web::get().to(template::get_project_template_by_id))
+            .route("/projects/{template_id}", web::delete().to(template::delete_project_template_entry))
+            .route("/projects/{template_id}/apply", web::post().to(template::apply_project_template))
+    );
 }
 
 pub fn init_uploads(cfg: &mut actix_web::web::ServiceConfig) {
@@ -55,9 +125,43 @@ pub fn init_uploads(cfg: &mut actix_web::web::ServiceConfig) {
         actix_web::web::resource("/avatars/get/{user_id}")
             .get(user::get_avatar)
     );
+    cfg.service(
+        actix_web::web::resource("/projects/{project_id}/icon")
+            .get(project::get_icon)
+    );
     cfg.service(
         actix_web::web::resource("/avatars/update/me")
             .wrap(Authentication)
             .post(user::change_avatar)
     );
+    cfg.service(
+        actix_web::web::resource("/tasks/{task_id}/attachments")
+            .wrap(Authentication)
+            .post(attachment::upload_attachment)
+    );
+    cfg.service(
+        actix_web::web::resource("/attachments/{attachment_id}")
+            .wrap(Authentication)
+            .get(attachment::download_attachment)
+    );
+    cfg.service(
+        actix_web::web::resource("/storage/{key:.*}")
+            .get(storage::get_file)
+    );
+}
+
+pub fn init_events(cfg: &mut actix_web::web::ServiceConfig) {
+    cfg.service(
+        actix_web::web::resource("/projects/{project_id}/board")
+            .wrap(Authentication)
+            .get(project::get_board_events)
+    );
+}
+
+pub fn init_reports(cfg: &mut actix_web::web::ServiceConfig) {
+    cfg.service(
+        actix_web::web::resource("/projects/{project_id}/time-report.csv")
+            .wrap(Authentication)
+            .get(time_entry::export_time_report)
+    );
 }
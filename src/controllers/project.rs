@@ -1,6 +1,17 @@
-use actix_web::web::{Data, Json, Path, ReqData};
+use std::convert::Infallible;
+
+use actix_web::{
+    http::header::EntityTag,
+    web::{Bytes, Data, Json, Path, Query, ReqData},
+    HttpRequest, HttpResponse,
+};
 use apistos::api_operation;
 use garde::Validate;
+use tokio::time::{interval, Duration};
+use tokio_stream::{
+    wrappers::{BroadcastStream, IntervalStream},
+    StreamExt,
+};
 
 use crate::{
     models::{
@@ -8,17 +19,25 @@ use crate::{
             CreateProjectRequest,
             SelectProject,
             UpdateProjectRequest
-        }, user::SelectUser
+        }, sync::SelectProjectSync, user::{AvatarQuery, SelectUser},
+        workflow::{SelectWorkflow, UpdateWorkflowRequest}, board::SelectBoard,
+        label::{CreateLabelRequest, SelectLabel, UpdateLabelRequest}
     },
-    services::{notifications::create_notification, project::{
+    controllers::user::{parse_fallback_format, AVATAR_MAX_AGE},
+    services::{avatar::{avatar_size, get_project_icon, render_fallback}, notifications::create_notification, project::{
         add_project_member,
         get_project_by_id,
         get_user_projects,
-        remove_project_member
-    }},
-    utils::{app_data::AppData, response::{ErrorResponse, SuccessResponse}}
+        remove_project_member,
+        PROJECT_CHANGED
+    }, common::VersionedUpdateError, sync::{get_project_sync, request_project_sync}, workflow::{get_workflow, update_workflow}, board::get_board, task::require_project_participant,
+        label::{create_label, delete_label, get_project_labels, update_label}, watcher::set_project_watching},
+    utils::{app_data::AppData, cache::{conditional_response, if_match_version}, response::{ErrorResponse, SuccessResponse, VersionedResponse}}
 };
 
+/// Comment sent over idle board streams so proxies keep them open.
+const BOARD_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
 #[api_operation(
     summary = "Get my projects",
     description = "Get all projects of the current user",
@@ -34,17 +53,18 @@ pub async fn get_my(user_id: ReqData<u64>) -> Result<Json<SuccessResponse<Vec<Se
 
 #[api_operation(
     summary = "Get project by id",
-    description = "Get project by id",
+    description = "Get project by id. Its version is sent as the ETag",
     tag = "Projects",
     error_code = "401",
     error_code = "404"
 )]
-pub async fn get_by_id(owner_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<SelectProject>>, ErrorResponse> {
+pub async fn get_by_id(owner_id: ReqData<u64>, project_id: Path<u64>) -> Result<VersionedResponse<SelectProject>, ErrorResponse> {
     let project = get_project_by_id(*owner_id, *project_id).await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
         .ok_or_else(|| ErrorResponse::NotFound("Project not found".to_string()))?;
 
-    Ok(Json(SuccessResponse::new(project)))
+    let version = project.version;
+    Ok(VersionedResponse::new(project, version))
 }
 
 #[api_operation(
@@ -76,20 +96,37 @@ pub async fn create_project(
 
 #[api_operation(
     summary = "Update project",
-    description = "Update project by id",
+    description = "Update project by id. With If-Match set to the ETag of the project, a project changed since then is not updated and 409 is returned with its current state",
     tag = "Projects",
     error_code = "400",
     error_code = "401",
-    error_code = "404"
+    error_code = "404",
+    error_code = "409"
 )]
-pub async fn update_project(owner_id: ReqData<u64>, project_id: Path<u64>, body: Json<UpdateProjectRequest>) -> Result<Json<SuccessResponse<SelectProject>>, ErrorResponse> {
+pub async fn update_project(
+    app_data: Data<AppData>,
+    req: HttpRequest,
+    owner_id: ReqData<u64>,
+    project_id: Path<u64>,
+    body: Json<UpdateProjectRequest>
+) -> Result<VersionedResponse<SelectProject>, ErrorResponse> {
     body.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+    let expected_version = if_match_version(&req)?;
 
-    let project = crate::services::project::update_project(*owner_id, *project_id, &*body).await
-        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
-        .ok_or_else(|| ErrorResponse::NotFound("Project not found".to_string()))?;
+    let project = match crate::services::project::update_project(&app_data.repositories, *owner_id, *project_id, &*body, expected_version).await {
+        Ok(project) => project.ok_or_else(|| ErrorResponse::NotFound("Project not found".to_string()))?,
+        // The project was changed since the version the client based the update on
+        Err(VersionedUpdateError::Changed) => {
+            let current = get_project_by_id(*owner_id, *project_id).await
+                .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+                .ok_or_else(|| ErrorResponse::NotFound("Project not found".to_string()))?;
+            return Err(ErrorResponse::conflict(PROJECT_CHANGED.to_string(), &current));
+        }
+        Err(VersionedUpdateError::Failed(err)) => return Err(ErrorResponse::InternalServerError(err)),
+    };
 
-    Ok(Json(SuccessResponse::new(project)))
+    let version = project.version;
+    Ok(VersionedResponse::new(project, version))
 }
 
 #[api_operation(
@@ -187,3 +224,231 @@ pub async fn remove_member(
 
     Ok(Json(SuccessResponse::new(members)))
 }
+
+#[api_operation(
+    summary = "Get project sync state",
+    description = "Get the repository sync state and recent sync history of a project",
+    tag = "Projects",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_sync(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<SelectProjectSync>>, ErrorResponse> {
+    let sync = get_project_sync(*user_id, *project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(sync)))
+}
+
+#[api_operation(
+    summary = "Trigger project sync",
+    description = "Request an immediate repository sync of a project. Only the owner can trigger it",
+    tag = "Projects",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn trigger_sync(
+    app_data: Data<AppData>,
+    owner_id: ReqData<u64>,
+    project_id: Path<u64>
+) -> Result<Json<SuccessResponse<SelectProjectSync>>, ErrorResponse> {
+    let sync = request_project_sync(*owner_id, *project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    app_data.sync_trigger.notify_one();
+
+    Ok(Json(SuccessResponse::new(sync)))
+}
+
+#[api_operation(
+    summary = "Get project workflow",
+    description = "Get the statuses of a project in board order and the transitions allowed between them",
+    tag = "Projects",
+    error_code = "401"
+)]
+pub async fn get_project_workflow(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<SelectWorkflow>>, ErrorResponse> {
+    let workflow = get_workflow(*user_id, *project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(workflow)))
+}
+
+#[api_operation(
+    summary = "Update project workflow",
+    description = "Replace the statuses and allowed transitions of a project. Only the owner can change them, and statuses that still have tasks can't be removed",
+    tag = "Projects",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn update_project_workflow(
+    owner_id: ReqData<u64>,
+    project_id: Path<u64>,
+    body: Json<UpdateWorkflowRequest>
+) -> Result<Json<SuccessResponse<SelectWorkflow>>, ErrorResponse> {
+    body.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let workflow = update_workflow(*owner_id, *project_id, &*body).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(workflow)))
+}
+
+#[api_operation(
+    summary = "Get project board",
+    description = "Get the tasks of a project grouped by status, in board order",
+    tag = "Projects",
+    error_code = "401"
+)]
+pub async fn get_project_board(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<SelectBoard>>, ErrorResponse> {
+    let board = get_board(*user_id, *project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(board)))
+}
+
+#[api_operation(
+    summary = "Get project labels",
+    description = "Get the labels of a project",
+    tag = "Projects",
+    error_code = "401"
+)]
+pub async fn get_labels(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectLabel>>>, ErrorResponse> {
+    let labels = get_project_labels(*user_id, *project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(labels)))
+}
+
+#[api_operation(
+    summary = "Create label",
+    description = "Create a colored label in a project",
+    tag = "Projects",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_project_label(
+    user_id: ReqData<u64>,
+    project_id: Path<u64>,
+    body: Json<CreateLabelRequest>
+) -> Result<Json<SuccessResponse<SelectLabel>>, ErrorResponse> {
+    body.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let label = create_label(*user_id, *project_id, &*body).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(label)))
+}
+
+#[api_operation(
+    summary = "Update label",
+    description = "Rename or recolor a label of a project",
+    tag = "Projects",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn update_project_label(
+    user_id: ReqData<u64>,
+    path: Path<(u64, u64)>,
+    body: Json<UpdateLabelRequest>
+) -> Result<Json<SuccessResponse<SelectLabel>>, ErrorResponse> {
+    body.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let (project_id, label_id) = path.into_inner();
+    let label = update_label(*user_id, project_id, label_id, &*body).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Label not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(label)))
+}
+
+#[api_operation(
+    summary = "Delete label",
+    description = "Delete a label of a project and remove it from its tasks",
+    tag = "Projects",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_project_label(user_id: ReqData<u64>, path: Path<(u64, u64)>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    let (project_id, label_id) = path.into_inner();
+    delete_label(*user_id, project_id, label_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Label not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+#[api_operation(
+    summary = "Watch project",
+    description = "Subscribe the current user to status, due date and description changes of every task in a project",
+    tag = "Projects",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn watch_project(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    set_project_watching(*user_id, *project_id, true).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+#[api_operation(
+    summary = "Unwatch project",
+    description = "Unsubscribe the current user from the changes in a project. Tasks watched directly stay watched",
+    tag = "Projects",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn unwatch_project(user_id: ReqData<u64>, project_id: Path<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    set_project_watching(*user_id, *project_id, false).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+/// Streams the task moves on a project board as server-sent events.
+pub async fn get_board_events(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    project_id: Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    require_project_participant(*user_id, *project_id).await
+        .map_err(ErrorResponse::Unauthorized)?;
+
+    let project_id = *project_id;
+    let events = BroadcastStream::new(app_data.board_events.subscribe())
+        // A viewer that lagged behind only misses the skipped moves and keeps listening
+        .filter_map(move |event| match event {
+            Ok(event) if event.project_id == project_id => serde_json::to_string(&event)
+                .ok()
+                .map(|data| format!("event: task_moved\ndata: {}\n\n", data)),
+            _ => None,
+        });
+    let keep_alive = IntervalStream::new(interval(BOARD_KEEP_ALIVE)).map(|_| ": keep-alive\n\n".to_string());
+    let stream = events
+        .merge(keep_alive)
+        .map(|message| Ok::<_, Infallible>(Bytes::from(message)));
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(stream))
+}
+
+pub async fn get_icon(
+    req: HttpRequest,
+    project_id: Path<u64>,
+    query: Query<AvatarQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let size = avatar_size(query.size);
+    let format = parse_fallback_format(query.format.as_deref())?;
+    let icon = get_project_icon(*project_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Project not found".to_string()))?;
+
+    let (mut response, not_modified) = conditional_response(&req, EntityTag::new_strong(icon.version(size, format)), AVATAR_MAX_AGE);
+    if not_modified {
+        return Ok(response.finish());
+    }
+    let (body, content_type) = render_fallback(&icon, size, format).map_err(ErrorResponse::InternalServerError)?;
+    Ok(response.content_type(content_type).body(body))
+}
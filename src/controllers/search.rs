@@ -0,0 +1,25 @@
+use actix_web::web::{Json, Query, ReqData};
+use apistos::api_operation;
+use garde::Validate;
+
+use crate::{
+    models::search::{SearchQuery, SelectSearchResults},
+    services::search::full_text_search,
+    utils::response::{ErrorResponse, SuccessResponse},
+};
+
+#[api_operation(
+    summary = "Search",
+    description = "Full-text search in Russian and English across projects, tasks and comments of the current user's projects, most relevant first",
+    tag = "Search",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn search(user_id: ReqData<u64>, query: Query<SearchQuery>) -> Result<Json<SuccessResponse<SelectSearchResults>>, ErrorResponse> {
+    query.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let results = full_text_search(*user_id, &*query).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(results)))
+}
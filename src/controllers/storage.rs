@@ -0,0 +1,38 @@
+use actix_web::{
+    http::header,
+    web::{Data, Path, Query},
+    HttpResponse,
+};
+
+use crate::{
+    models::storage::PresignedUrlQuery,
+    storage::ResponseOverrides,
+    utils::{app_data::AppData, response::ErrorResponse}
+};
+
+/// Serves files of the local storage through the URLs it presigns.
+pub async fn get_file(
+    app_data: Data<AppData>,
+    key: Path<String>,
+    query: Query<PresignedUrlQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let query = query.into_inner();
+    let overrides = ResponseOverrides {
+        content_type: query.content_type,
+        content_disposition: query.content_disposition,
+    };
+    if !app_data.storage.verify_presigned_url(&key, query.expires, &overrides, &query.signature) {
+        return Err(ErrorResponse::Unauthorized("Invalid or expired link".to_string()));
+    }
+
+    let data = app_data.storage.get(&key).await
+        .map_err(|e| ErrorResponse::InternalServerError(format!("{:#}", e)))?
+        .ok_or_else(|| ErrorResponse::NotFound("File not found".to_string()))?;
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(overrides.content_type.unwrap_or_else(|| "application/octet-stream".to_string()));
+    if let Some(content_disposition) = overrides.content_disposition {
+        response.insert_header((header::CONTENT_DISPOSITION, content_disposition));
+    }
+    Ok(response.body(data))
+}
@@ -1,12 +1,20 @@
-use actix_web::web::{Data, Json, Path, Query, ReqData};
+use actix_web::{web::{Data, Json, Path, Query, ReqData}, HttpRequest};
 use apistos::api_operation;
 use garde::Validate;
 
 use crate::{
     models::{
-        task::{CreateTaskRequest, SelectTask, SelectTaskRequest, UpdateTaskRequest}, user::SelectUser},
-    services::{notifications::create_notification, task::{add_assigned_user, get_task_by_id, get_user_tasks, remove_assigned_user}},
-    utils::{app_data::AppData, response::{ErrorResponse, SuccessResponse}}
+        actor::Actor,
+        board::{BoardEvent, MoveTaskRequest},
+        bulk::{BulkTaskRequest, SelectBulkTaskResponse},
+        dependency::{CreateDependencyRequest, SelectTaskDependencies},
+        discussion::{CreateDiscussionReplyRequest, SelectExternalComment, SelectTaskDiscussion},
+        history::{SelectTaskHistoryPage, SelectTaskHistoryRequest, SelectTaskRevert},
+        recurrence::{SelectRecurrence, SetRecurrenceRequest},
+        task::{CreateSubtaskRequest, CreateTaskRequest, DeleteTaskQuery, ReorderSubtasksRequest, SelectMyTasksRequest, SelectTask, SelectTaskPage, SelectTaskRequest, UpdateTaskRequest},
+        transfer::{DuplicateTaskRequest, MoveTaskToProjectRequest, SelectMovedTask}, user::SelectUser},
+    services::{board::{move_task_on_board, publish_board_event}, bulk::bulk_update_tasks, common::VersionedUpdateError, dependency::{add_dependency, get_blocked_task_ids, get_task_dependencies, propagate_status_change, remove_dependency}, discussion::{get_task_discussion, post_discussion_reply}, history::{get_task_history, revert_task_change}, notifications::create_notification, recurrence::{get_task_recurrence, set_task_recurrence, stop_task_recurrence}, task::{add_assigned_user, get_subtasks, get_task_by_id, get_tasks, get_user_tasks, remove_assigned_user, TaskQueryError, TASK_CHANGED}, transfer::{duplicate_task, move_task_to_project}, watcher::{add_task_changes, get_task_watchers, notify_task_watchers, unwatch_task, watch_task, WatcherDigest}},
+    utils::{app_data::AppData, cache::if_match_version, response::{ErrorResponse, SuccessResponse, VersionedResponse}}
 };
 
 #[api_operation(
@@ -15,26 +23,47 @@ use crate::{
     tag = "Tasks",
     error_code = "401"
 )]
-pub async fn get_my(user_id: ReqData<u64>, filters: Query<SelectTaskRequest>) -> Result<Json<SuccessResponse<Vec<SelectTask>>>, ErrorResponse> {
+pub async fn get_my(user_id: ReqData<u64>, filters: Query<SelectMyTasksRequest>) -> Result<Json<SuccessResponse<Vec<SelectTask>>>, ErrorResponse> {
     let projects = get_user_tasks(*user_id, &*filters).await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
     Ok(Json(SuccessResponse::new(projects)))
 }
 
+#[api_operation(
+    summary = "Get tasks",
+    description = "Get the tasks assigned to the current user, or all tasks of a project. Supports filters, sorting and cursor pagination",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_list(user_id: ReqData<u64>, filters: Query<SelectTaskRequest>) -> Result<Json<SuccessResponse<SelectTaskPage>>, ErrorResponse> {
+    filters.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let page = get_tasks(*user_id, &*filters).await.map_err(|e| match e {
+        TaskQueryError::InvalidFilters(err) => ErrorResponse::BadRequest(err),
+        TaskQueryError::ProjectNotFound => ErrorResponse::NotFound("Project not found".to_string()),
+        TaskQueryError::Failed(err) => ErrorResponse::InternalServerError(err),
+    })?;
+
+    Ok(Json(SuccessResponse::new(page)))
+}
+
 #[api_operation(
     summary = "Get task by id",
-    description = "Get a task by its id",
+    description = "Get a task by its id. Its version is sent as the ETag",
     tag = "Tasks",
     error_code = "401",
     error_code = "404"
 )]
-pub async fn get_by_id(_user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectTask>>, ErrorResponse> {
+pub async fn get_by_id(_user_id: ReqData<u64>, task_id: Path<u64>) -> Result<VersionedResponse<SelectTask>, ErrorResponse> {
     let task = get_task_by_id(*task_id).await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
         .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
 
-    Ok(Json(SuccessResponse::new(task)))
+    let version = task.version;
+    Ok(VersionedResponse::new(task, version))
 }
 
 #[api_operation(
@@ -52,7 +81,7 @@ pub async fn create_task(
     task.validate()
         .map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
 
-    let task = crate::services::task::create_task(*user_id, &*task)
+    let task = crate::services::task::create_task(Actor::User(*user_id), &*task)
         .await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
@@ -70,33 +99,84 @@ pub async fn create_task(
 
 #[api_operation(
     summary = "Update task",
-    description = "Update task by id",
+    description = "Update task by id. With If-Match set to the ETag of the task, a task changed since then is not updated and 409 is returned with its current state",
     tag = "Tasks",
     error_code = "400",
     error_code = "401",
-    error_code = "404"
+    error_code = "404",
+    error_code = "409"
 )]
-pub async fn update_task(user_id: ReqData<u64>, task_id: Path<u64>, task: Json<UpdateTaskRequest>) -> Result<Json<SuccessResponse<SelectTask>>, ErrorResponse> {
+pub async fn update_task(
+    app_data: Data<AppData>,
+    req: HttpRequest,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    task: Json<UpdateTaskRequest>
+) -> Result<VersionedResponse<SelectTask>, ErrorResponse> {
     task.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+    let expected_version = if_match_version(&req)?;
 
-    let task = crate::services::task::update_task(*user_id, *task_id, &*task).await
+    let previous = get_task_by_id(*task_id).await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
         .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
 
-    Ok(Json(SuccessResponse::new(task)))
+    let task = match crate::services::task::update_task(Actor::User(*user_id), *task_id, &*task, expected_version).await {
+        Ok(task) => task.ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?,
+        Err(err) => return Err(task_update_error(*task_id, err).await),
+    };
+    notify_task_watchers(&app_data.mailer, &Actor::User(*user_id), &previous, &task).await;
+
+    if task.status == previous.status {
+        let version = task.version;
+        return Ok(VersionedResponse::new(task, version));
+    }
+    let blocked_task_ids = get_blocked_task_ids(*task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+    propagate_status_change(&app_data.mailer, &Actor::User(*user_id), *task_id, &blocked_task_ids).await;
+
+    // The task itself is blocked again if it still has unfinished blockers
+    let task = get_task_by_id(*task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    let version = task.version;
+    Ok(VersionedResponse::new(task, version))
+}
+
+/// Answers an update of a task changed since the version the client based it on with its current state.
+async fn task_update_error(task_id: u64, err: VersionedUpdateError) -> ErrorResponse {
+    match err {
+        VersionedUpdateError::Changed => match get_task_by_id(task_id).await {
+            Ok(Some(current)) => ErrorResponse::conflict(TASK_CHANGED.to_string(), &current),
+            Ok(None) => ErrorResponse::NotFound("Task not found".to_string()),
+            Err(err) => ErrorResponse::InternalServerError(err),
+        },
+        VersionedUpdateError::Failed(err) => ErrorResponse::BadRequest(err),
+    }
 }
 
 #[api_operation(
     summary = "Delete task",
-    description = "Delete task by id",
+    description = "Delete task by id. Its subtasks move to its parent unless cascade is set",
     tag = "Tasks",
     error_code = "401",
     error_code = "404"
 )]
-pub async fn delete_task(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
-    crate::services::task::delete_task(*user_id, *task_id).await
+pub async fn delete_task(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    query: Query<DeleteTaskQuery>
+) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    let blocked_task_ids = get_blocked_task_ids(*task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    crate::services::task::delete_task(*user_id, *task_id, &*query).await
         .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
+    // The links of a deleted task are gone, so the tasks it blocked may be free now
+    propagate_status_change(&app_data.mailer, &Actor::User(*user_id), *task_id, &blocked_task_ids).await;
+
     Ok(Json(SuccessResponse::new(())))
 }
 
@@ -155,3 +235,474 @@ pub async fn remove_assignee(
 
     Ok(Json(SuccessResponse::new(users)))
 }
+
+#[api_operation(
+    summary = "Get task discussion",
+    description = "Get the comments of the issue linked to a task",
+    tag = "Tasks",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_discussion(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectTaskDiscussion>>, ErrorResponse> {
+    let discussion = get_task_discussion(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(discussion)))
+}
+
+#[api_operation(
+    summary = "Reply to task discussion",
+    description = "Post a comment to the issue linked to a task",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn reply_to_discussion(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    reply: Json<CreateDiscussionReplyRequest>
+) -> Result<Json<SuccessResponse<SelectExternalComment>>, ErrorResponse> {
+    reply.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let comment = post_discussion_reply(&app_data.issue_providers, &app_data.repositories.token_key, *user_id, *task_id, &reply.body).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(comment)))
+}
+
+#[api_operation(
+    summary = "Get subtasks",
+    description = "Get the direct subtasks of a task in their order",
+    tag = "Tasks",
+    error_code = "401"
+)]
+pub async fn get_subtasks_of(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectTask>>>, ErrorResponse> {
+    let subtasks = get_subtasks(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(subtasks)))
+}
+
+#[api_operation(
+    summary = "Create subtask",
+    description = "Create a task under another task of the same project",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_subtask(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    subtask: Json<CreateSubtaskRequest>
+) -> Result<Json<SuccessResponse<SelectTask>>, ErrorResponse> {
+    subtask.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let subtask = crate::services::task::create_subtask(*user_id, *task_id, &*subtask).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    for user in &subtask.attached_to {
+        create_notification(
+            "Вас назначили на задачу".to_string(),
+            format!("Вы были назначены на задачу {}. Думаю, вам стоит проверить ваш личный кабинет", subtask.name).to_string(),
+            user.id,
+            &app_data.mailer
+        ).await;
+    }
+
+    Ok(Json(SuccessResponse::new(subtask)))
+}
+
+#[api_operation(
+    summary = "Reorder subtasks",
+    description = "Set the order of the subtasks of a task",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn reorder_subtasks(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    order: Json<ReorderSubtasksRequest>
+) -> Result<Json<SuccessResponse<Vec<SelectTask>>>, ErrorResponse> {
+    order.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let subtasks = crate::services::task::reorder_subtasks(*user_id, *task_id, &*order).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(subtasks)))
+}
+
+#[api_operation(
+    summary = "Get task dependencies",
+    description = "Get the tasks a task blocks and the tasks it is blocked by",
+    tag = "Tasks",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_dependencies(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectTaskDependencies>>, ErrorResponse> {
+    let dependencies = get_task_dependencies(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(dependencies)))
+}
+
+#[api_operation(
+    summary = "Add task dependency",
+    description = "Mark the task as blocked by another task of the same project. The task stays blocked until all its blockers are done or cancelled",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_dependency(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    dependency: Json<CreateDependencyRequest>
+) -> Result<Json<SuccessResponse<SelectTaskDependencies>>, ErrorResponse> {
+    let dependencies = add_dependency(&app_data.mailer, *user_id, *task_id, dependency.blocker_id).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(dependencies)))
+}
+
+#[api_operation(
+    summary = "Remove task dependency",
+    description = "Remove a blocker of the task",
+    tag = "Tasks",
+    error_code = "401"
+)]
+pub async fn delete_dependency(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    path: Path<(u64, u64)>
+) -> Result<Json<SuccessResponse<SelectTaskDependencies>>, ErrorResponse> {
+    let (task_id, blocker_id) = path.into_inner();
+    let dependencies = remove_dependency(&app_data.mailer, *user_id, task_id, blocker_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(dependencies)))
+}
+
+#[api_operation(
+    summary = "Move task on board",
+    description = "Move a task to a place in a status column of the project board. Other viewers of the board are notified. With If-Match set to the ETag of the task, a task changed since then is not moved and 409 is returned with its current state",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404",
+    error_code = "409"
+)]
+pub async fn move_task(
+    app_data: Data<AppData>,
+    req: HttpRequest,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    request: Json<MoveTaskRequest>
+) -> Result<VersionedResponse<SelectTask>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+    let expected_version = if_match_version(&req)?;
+
+    let previous = get_task_by_id(*task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    let mut task = match move_task_on_board(*user_id, *task_id, &*request, expected_version).await {
+        Ok(task) => task.ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?,
+        Err(err) => return Err(task_update_error(*task_id, err).await),
+    };
+
+    if task.status != previous.status {
+        notify_task_watchers(&app_data.mailer, &Actor::User(*user_id), &previous, &task).await;
+        let blocked_task_ids = get_blocked_task_ids(*task_id).await
+            .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+        propagate_status_change(&app_data.mailer, &Actor::User(*user_id), *task_id, &blocked_task_ids).await;
+        task = get_task_by_id(*task_id).await
+            .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+            .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+    }
+
+    // The move is done either way, a failed notice only leaves boards stale until reloaded
+    publish_board_event(&BoardEvent {
+        project_id: task.project.id,
+        task_id: task.id,
+        status: task.status.clone(),
+        board_position: task.board_position,
+        moved_by: *user_id,
+    }).await.ok();
+
+    let version = task.version;
+    Ok(VersionedResponse::new(task, version))
+}
+
+#[api_operation(
+    summary = "Get task history",
+    description = "Get the field changes of a task, newest first",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn get_history(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    query: Query<SelectTaskHistoryRequest>
+) -> Result<Json<SuccessResponse<SelectTaskHistoryPage>>, ErrorResponse> {
+    query.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let history = get_task_history(*user_id, *task_id, &*query).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(history)))
+}
+
+#[api_operation(
+    summary = "Revert task change",
+    description = "Restore the value a task field had before the given change. Former assignees who left the project are not assigned again and are reported. With If-Match set to the ETag of the task, a task changed since then is not updated and 409 is returned with its current state",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404",
+    error_code = "409"
+)]
+pub async fn revert_change(
+    app_data: Data<AppData>,
+    req: HttpRequest,
+    user_id: ReqData<u64>,
+    path: Path<(u64, u64)>
+) -> Result<VersionedResponse<SelectTaskRevert>, ErrorResponse> {
+    let (task_id, change_id) = path.into_inner();
+    let expected_version = if_match_version(&req)?;
+
+    let previous = get_task_by_id(task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    let SelectTaskRevert { task, skipped_assignee_ids } = match revert_task_change(*user_id, task_id, change_id, expected_version).await {
+        Ok(revert) => revert.ok_or_else(|| ErrorResponse::NotFound("Change not found".to_string()))?,
+        Err(err) => return Err(task_update_error(task_id, err).await),
+    };
+    notify_task_watchers(&app_data.mailer, &Actor::User(*user_id), &previous, &task).await;
+
+    if task.status == previous.status {
+        let version = task.version;
+        return Ok(VersionedResponse::new(SelectTaskRevert { task, skipped_assignee_ids }, version));
+    }
+    let blocked_task_ids = get_blocked_task_ids(task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+    propagate_status_change(&app_data.mailer, &Actor::User(*user_id), task_id, &blocked_task_ids).await;
+
+    let task = get_task_by_id(task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    let version = task.version;
+    Ok(VersionedResponse::new(SelectTaskRevert { task, skipped_assignee_ids }, version))
+}
+
+#[api_operation(
+    summary = "Change tasks in bulk",
+    description = "Apply a status, assignee, label or due date change, or a deletion, to several tasks at once. Each task is reported separately, assignees and watchers get one notification for all of their tasks",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn bulk_update(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    request: Json<BulkTaskRequest>
+) -> Result<Json<SuccessResponse<SelectBulkTaskResponse>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let mut previous = vec![];
+    if request.status.is_some() || request.due_date.is_some() || request.clear_due_date.unwrap_or(false) {
+        for task_id in &request.task_ids {
+            if let Ok(Some(task)) = get_task_by_id(*task_id).await {
+                previous.push(task);
+            }
+        }
+    }
+
+    // Watchers get one notification for all of their tasks
+    let mut digest = WatcherDigest::default();
+    let response = bulk_update_tasks(&app_data.mailer, &mut digest, *user_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    for task in response.results.iter().filter_map(|result| result.task.as_ref()) {
+        let Some(previous) = previous.iter().find(|previous| previous.id == task.id) else {
+            continue;
+        };
+        add_task_changes(&mut digest, &Actor::User(*user_id), previous, task).await;
+        if task.status == previous.status {
+            continue;
+        }
+        // The move is done either way, a failed notice only leaves boards stale until reloaded
+        publish_board_event(&BoardEvent {
+            project_id: task.project.id,
+            task_id: task.id,
+            status: task.status.clone(),
+            board_position: task.board_position,
+            moved_by: *user_id,
+        }).await.ok();
+    }
+    digest.send(&app_data.mailer).await;
+
+    Ok(Json(SuccessResponse::new(response)))
+}
+
+#[api_operation(
+    summary = "Get task recurrence",
+    description = "Get the recurring series a task belongs to",
+    tag = "Tasks",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_recurrence(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectRecurrence>>, ErrorResponse> {
+    let recurrence = get_task_recurrence(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task is not recurring".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(recurrence)))
+}
+
+#[api_operation(
+    summary = "Set task recurrence",
+    description = "Make a task recurring or change the rule of its series. The next instance is created once the current one is finished or due",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn set_recurrence(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    request: Json<SetRecurrenceRequest>
+) -> Result<Json<SuccessResponse<SelectRecurrence>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let recurrence = set_task_recurrence(*user_id, *task_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(recurrence)))
+}
+
+#[api_operation(
+    summary = "Stop task recurrence",
+    description = "Stop the recurring series of a task. Existing instances are kept",
+    tag = "Tasks",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn stop_recurrence(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectRecurrence>>, ErrorResponse> {
+    let recurrence = stop_task_recurrence(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task is not recurring".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(recurrence)))
+}
+
+#[api_operation(
+    summary = "Move task to another project",
+    description = "Move a task with its subtasks to another project the current user participates in. Assignees who are not participants of the target are rejected or, with remove_non_members, unassigned and reported. The linked issue is cleared unless keep_issue is set",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn move_to_project(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    request: Json<MoveTaskToProjectRequest>
+) -> Result<Json<SuccessResponse<SelectMovedTask>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let moved = move_task_to_project(&app_data.mailer, *user_id, *task_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    for user in &moved.removed_assignees {
+        create_notification(
+            "Вас удалили с задачи".to_string(),
+            format!("Задача {} перенесена в проект {}, в котором вы не участвуете.", moved.task.name, moved.task.project.name).to_string(),
+            user.id,
+            &app_data.mailer
+        ).await;
+    }
+    // The move is done either way, a failed notice only leaves boards stale until reloaded
+    publish_board_event(&BoardEvent {
+        project_id: moved.task.project.id,
+        task_id: moved.task.id,
+        status: moved.task.status.clone(),
+        board_position: moved.task.board_position,
+        moved_by: *user_id,
+    }).await.ok();
+
+    Ok(Json(SuccessResponse::new(moved)))
+}
+
+#[api_operation(
+    summary = "Duplicate task",
+    description = "Create a copy of a task next to it, optionally with its assignees, description and subtasks",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn duplicate(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    request: Json<DuplicateTaskRequest>
+) -> Result<Json<SuccessResponse<SelectTask>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let task = duplicate_task(*user_id, *task_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Task not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(task)))
+}
+
+#[api_operation(
+    summary = "Get task watchers",
+    description = "Get the users watching a task. Watchers of its project are not listed",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn get_watchers(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectUser>>>, ErrorResponse> {
+    let watchers = get_task_watchers(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(watchers)))
+}
+
+#[api_operation(
+    summary = "Watch task",
+    description = "Subscribe the current user to status, due date and description changes of a task",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn watch(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectUser>>>, ErrorResponse> {
+    let watchers = watch_task(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(watchers)))
+}
+
+#[api_operation(
+    summary = "Unwatch task",
+    description = "Unsubscribe the current user from the changes of a task",
+    tag = "Tasks",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn unwatch(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectUser>>>, ErrorResponse> {
+    let watchers = unwatch_task(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(watchers)))
+}
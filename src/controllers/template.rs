@@ -0,0 +1,225 @@
+use actix_web::web::{Data, Json, Path, ReqData};
+use apistos::api_operation;
+use garde::Validate;
+
+use crate::{
+    models::{
+        project::SelectProject,
+        task::SelectTask,
+        template::{
+            CreateProjectFromTemplateRequest, CreateProjectTemplateRequest, CreateTaskFromTemplateRequest,
+            SaveProjectTemplateRequest, SelectProjectTemplate, SelectTaskTemplate, TaskTemplateRequest,
+        },
+    },
+    services::{
+        notifications::create_notification,
+        template::{
+            create_project_from_template, create_project_template, create_task_from_template, create_task_template,
+            delete_project_template, delete_task_template, get_project_template, get_project_templates,
+            get_task_templates, save_project_as_template,
+        },
+    },
+    utils::{app_data::AppData, response::{ErrorResponse, SuccessResponse}},
+};
+
+#[api_operation(
+    summary = "Get task templates",
+    description = "Get the task templates of the current user that are not part of a project template",
+    tag = "Templates",
+    error_code = "401"
+)]
+pub async fn get_task_templates_list(user_id: ReqData<u64>) -> Result<Json<SuccessResponse<Vec<SelectTaskTemplate>>>, ErrorResponse> {
+    let templates = get_task_templates(*user_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(templates)))
+}
+
+#[api_operation(
+    summary = "Create task template",
+    description = "Create a task template. Assignee roles are filled with users when a task is created from it",
+    tag = "Templates",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_task_template_entry(
+    user_id: ReqData<u64>,
+    request: Json<TaskTemplateRequest>
+) -> Result<Json<SuccessResponse<SelectTaskTemplate>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let template = create_task_template(*user_id, &*request).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(template)))
+}
+
+#[api_operation(
+    summary = "Delete task template",
+    description = "Delete a task template of the current user",
+    tag = "Templates",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_task_template_entry(user_id: ReqData<u64>, template_id: Path<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    delete_task_template(*user_id, *template_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Template not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+#[api_operation(
+    summary = "Create task from template",
+    description = "Create a task in a project from a task template. The due date is the start date plus the offset of the template",
+    tag = "Templates",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn apply_task_template(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    template_id: Path<u64>,
+    request: Json<CreateTaskFromTemplateRequest>
+) -> Result<Json<SuccessResponse<SelectTask>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let task = create_task_from_template(*user_id, *template_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Template not found".to_string()))?;
+
+    for user in &task.attached_to {
+        create_notification(
+            "Вас назначили на задачу".to_string(),
+            format!("Вы были назначены на задачу {}. Думаю, вам стоит проверить ваш личный кабинет", task.name).to_string(),
+            user.id,
+            &app_data.mailer
+        ).await;
+    }
+
+    Ok(Json(SuccessResponse::new(task)))
+}
+
+#[api_operation(
+    summary = "Get project templates",
+    description = "Get the project templates of the current user",
+    tag = "Templates",
+    error_code = "401"
+)]
+pub async fn get_project_templates_list(user_id: ReqData<u64>) -> Result<Json<SuccessResponse<Vec<SelectProjectTemplate>>>, ErrorResponse> {
+    let templates = get_project_templates(*user_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(templates)))
+}
+
+#[api_operation(
+    summary = "Get project template",
+    description = "Get a project template with its roles and tasks",
+    tag = "Templates",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn get_project_template_by_id(
+    user_id: ReqData<u64>,
+    template_id: Path<u64>
+) -> Result<Json<SuccessResponse<SelectProjectTemplate>>, ErrorResponse> {
+    let template = get_project_template(*user_id, *template_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Template not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(template)))
+}
+
+#[api_operation(
+    summary = "Create project template",
+    description = "Create a project template from roles and task templates. Tasks are created in the given order",
+    tag = "Templates",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_project_template_entry(
+    user_id: ReqData<u64>,
+    request: Json<CreateProjectTemplateRequest>
+) -> Result<Json<SuccessResponse<SelectProjectTemplate>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let template = create_project_template(*user_id, request.into_inner()).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(template)))
+}
+
+#[api_operation(
+    summary = "Delete project template",
+    description = "Delete a project template with its task templates. Projects created from it are kept",
+    tag = "Templates",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_project_template_entry(user_id: ReqData<u64>, template_id: Path<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    delete_project_template(*user_id, *template_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Template not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+#[api_operation(
+    summary = "Create project from template",
+    description = "Create a project owned by the current user with the tasks of a template. Users filling the roles become members and are assigned to the tasks of their roles",
+    tag = "Templates",
+    error_code = "400",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn apply_project_template(
+    app_data: Data<AppData>,
+    user_id: ReqData<u64>,
+    template_id: Path<u64>,
+    request: Json<CreateProjectFromTemplateRequest>
+) -> Result<Json<SuccessResponse<SelectProject>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let project = create_project_from_template(*user_id, *template_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Template not found".to_string()))?;
+
+    create_notification(
+        "Новый проект".to_string(),
+        format!("Уважаемый пользователь, вы только что создали новый проект {} на сервисе Krakker.", project.name).to_string(),
+        project.owner.id,
+        &app_data.mailer
+    ).await;
+    for member in &project.members {
+        create_notification(
+            "Участник добавлен".to_string(),
+            format!("Вы только что были добавлены в проект {} в качестве участника.", project.name).to_string(),
+            member.id,
+            &app_data.mailer
+        ).await;
+    }
+
+    Ok(Json(SuccessResponse::new(project)))
+}
+
+#[api_operation(
+    summary = "Save project as template",
+    description = "Save the tasks of a project as a project template. Due offsets count from the creation of the project, members can be kept as roles",
+    tag = "Templates",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn save_project_template(
+    user_id: ReqData<u64>,
+    project_id: Path<u64>,
+    request: Json<SaveProjectTemplateRequest>
+) -> Result<Json<SuccessResponse<SelectProjectTemplate>>, ErrorResponse> {
+    request.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let template = save_project_as_template(*user_id, *project_id, &*request).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(template)))
+}
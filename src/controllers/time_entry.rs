@@ -0,0 +1,129 @@
+use actix_web::{
+    web::{Json, Path, Query, ReqData},
+    HttpResponse,
+};
+use apistos::api_operation;
+use garde::Validate;
+
+use crate::{
+    models::time_entry::{CreateWorklogRequest, SelectTimeEntry, SelectTimeReport, TimeReportQuery},
+    services::time_entry::{
+        create_worklog, delete_time_entry, get_task_time_entries, get_time_report, start_timer, stop_timer,
+        time_report_to_csv,
+    },
+    utils::response::{ErrorResponse, SuccessResponse},
+};
+
+#[api_operation(
+    summary = "Get task time entries",
+    description = "Get the timers and worklogs of a task, oldest first",
+    tag = "Time tracking",
+    error_code = "401"
+)]
+pub async fn get_time_entries(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<Vec<SelectTimeEntry>>>, ErrorResponse> {
+    let entries = get_task_time_entries(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(entries)))
+}
+
+#[api_operation(
+    summary = "Start timer",
+    description = "Start tracking time on a task. A user can run only one timer at a time",
+    tag = "Time tracking",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn start_task_timer(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectTimeEntry>>, ErrorResponse> {
+    let entry = start_timer(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(entry)))
+}
+
+#[api_operation(
+    summary = "Stop timer",
+    description = "Stop the running timer of the current user on a task",
+    tag = "Time tracking",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn stop_task_timer(user_id: ReqData<u64>, task_id: Path<u64>) -> Result<Json<SuccessResponse<SelectTimeEntry>>, ErrorResponse> {
+    let entry = stop_timer(*user_id, *task_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("No timer is running on the task".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(entry)))
+}
+
+#[api_operation(
+    summary = "Log time",
+    description = "Log time spent on a task after the fact",
+    tag = "Time tracking",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn create_task_worklog(
+    user_id: ReqData<u64>,
+    task_id: Path<u64>,
+    body: Json<CreateWorklogRequest>
+) -> Result<Json<SuccessResponse<SelectTimeEntry>>, ErrorResponse> {
+    body.validate().map_err(|error| ErrorResponse::BadRequest(error.to_string()))?;
+
+    let entry = create_worklog(*user_id, *task_id, &*body).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(entry)))
+}
+
+#[api_operation(
+    summary = "Delete time entry",
+    description = "Delete a timer or worklog entry of the current user",
+    tag = "Time tracking",
+    error_code = "401",
+    error_code = "404"
+)]
+pub async fn delete_task_time_entry(user_id: ReqData<u64>, path: Path<(u64, u64)>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    let (task_id, entry_id) = path.into_inner();
+    delete_time_entry(*user_id, task_id, entry_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?
+        .ok_or_else(|| ErrorResponse::NotFound("Time entry not found".to_string()))?;
+
+    Ok(Json(SuccessResponse::new(())))
+}
+
+#[api_operation(
+    summary = "Get time report",
+    description = "Get the time spent on a project per user and task within a period",
+    tag = "Time tracking",
+    error_code = "400",
+    error_code = "401"
+)]
+pub async fn get_project_time_report(
+    user_id: ReqData<u64>,
+    project_id: Path<u64>,
+    query: Query<TimeReportQuery>
+) -> Result<Json<SuccessResponse<SelectTimeReport>>, ErrorResponse> {
+    let report = get_time_report(*user_id, *project_id, &*query).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(Json(SuccessResponse::new(report)))
+}
+
+/// The time report as a CSV file for invoicing.
+pub async fn export_time_report(
+    user_id: ReqData<u64>,
+    project_id: Path<u64>,
+    query: Query<TimeReportQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let report = get_time_report(*user_id, *project_id, &*query).await
+        .map_err(|e| ErrorResponse::BadRequest(e.to_string()))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"time-report-{}.csv\"", report.project_id),
+        ))
+        .body(time_report_to_csv(&report)))
+}
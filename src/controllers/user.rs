@@ -1,24 +1,33 @@
-use std::fs::{self, File};
-use std::io;
+use std::str::FromStr;
 
-use actix_files::NamedFile;
 use actix_multipart::form::MultipartForm;
 use actix_web::{
-    http::header::ContentType,
-    web::{Json, Path, Query, ReqData},
-    HttpRequest, HttpResponse, Responder,
+    http::header::{ContentType, EntityTag},
+    web::{self, Data, Json, Path, Query, ReqData},
+    HttpRequest, HttpResponse,
 };
 
 use apistos::api_operation;
 
 use crate::{
-    models::user::{ChangeAvatar, SelectUser, SelectUserQuery},
-    services::user::{get_all_users, get_user},
-    utils::response::{ErrorResponse, SuccessResponse},
+    models::user::{AvatarQuery, ChangeAvatar, SelectUser, SelectUserQuery},
+    services::{
+        avatar::{avatar_key, avatar_size, get_user_avatar, render_avatar, render_fallback, save_avatar, FallbackFormat},
+        user::{get_all_users, get_user},
+    },
+    utils::{app_data::AppData, cache::conditional_response, response::{ErrorResponse, SuccessResponse}},
 };
 
-const AVATARS_PATH_DIR: &str = "/uploads/avatars";
-const DEFAULT_AVATAR: &[u8] = include_bytes!("../../static/default_avatar.png");
+/// Clients revalidate avatars with the ETag once this many seconds have passed.
+pub const AVATAR_MAX_AGE: u32 = 5 * 60;
+
+pub fn parse_fallback_format(format: Option<&str>) -> Result<FallbackFormat, ErrorResponse> {
+    match format {
+        Some(format) => FallbackFormat::from_str(format)
+            .map_err(|_| ErrorResponse::BadRequest("Unsupported format, expected svg or png".to_string())),
+        None => Ok(FallbackFormat::Svg),
+    }
+}
 
 #[api_operation(
     summary = "Get me",
@@ -49,29 +58,66 @@ pub async fn get_all(query: Query<SelectUserQuery>) -> Result<Json<SuccessRespon
     Ok(Json(SuccessResponse::new(users)))
 }
 
-pub async fn get_avatar(req: HttpRequest, user_id: Path<u64>) -> impl Responder {
-    let path = format!("{}/{}.png", AVATARS_PATH_DIR, user_id);
+pub async fn get_avatar(
+    req: HttpRequest,
+    app_data: Data<AppData>,
+    user_id: Path<u64>,
+    query: Query<AvatarQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let size = avatar_size(query.size);
+    let format = parse_fallback_format(query.format.as_deref())?;
+    let (updated_at, fallback) = get_user_avatar(*user_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
-    match NamedFile::open(&path) {
-        Ok(file) => file.use_last_modified(true).into_response(&req),
-        Err(_) => HttpResponse::Ok()
-            .content_type(ContentType::png())
-            .body(DEFAULT_AVATAR),
+    if let Some(updated_at) = updated_at {
+        let etag = EntityTag::new_strong(format!("{}-{}-{}", user_id, size, updated_at.timestamp_millis()));
+        let (mut response, not_modified) = conditional_response(&req, etag, AVATAR_MAX_AGE);
+        if not_modified {
+            return Ok(response.finish());
+        }
+        let avatar = app_data.storage.get(&avatar_key(*user_id, size)).await
+            .map_err(|e| ErrorResponse::InternalServerError(format!("{:#}", e)))?;
+        if let Some(avatar) = avatar {
+            return Ok(response.content_type(ContentType::png()).body(avatar));
+        }
+        log::error!(target: "UserController", "Avatar of user {} is missing from the storage", user_id);
     }
+
+    let (mut response, not_modified) = conditional_response(&req, EntityTag::new_strong(fallback.version(size, format)), AVATAR_MAX_AGE);
+    if not_modified {
+        return Ok(response.finish());
+    }
+    let (body, content_type) = render_fallback(&fallback, size, format).map_err(ErrorResponse::InternalServerError)?;
+    Ok(response.content_type(content_type).body(body))
 }
 
 pub async fn change_avatar(
+    app_data: Data<AppData>,
     user_id: ReqData<u64>,
     body: MultipartForm<ChangeAvatar>,
 ) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
-    let path = format!("{}/{}.png", AVATARS_PATH_DIR, *user_id);
+    let data = tokio::fs::read(body.file.file.path()).await.map_err(internal_server_error)?;
+
+    // Decoding and resizing is CPU bound, so it is kept off the async workers
+    let renditions = web::block(move || render_avatar(&data)).await
+        .map_err(internal_server_error)?
+        .map_err(ErrorResponse::BadRequest)?;
 
-    fs::create_dir_all(AVATARS_PATH_DIR).map_err(internal_server_error)?;
+    save_avatar(app_data.storage.as_ref(), *user_id, renditions).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
-    let mut file = File::create(path).map_err(internal_server_error)?;
-    let mut temp_file = body.file.file.as_file();
+    Ok(Json(SuccessResponse::new(())))
+}
 
-    io::copy(&mut temp_file, &mut file).map_err(internal_server_error)?;
+#[api_operation(
+    summary = "Delete my avatar",
+    description = "Delete the custom avatar of the authenticated user and go back to the default one",
+    tag = "Users",
+    error_code = "401"
+)]
+pub async fn delete_avatar(app_data: Data<AppData>, user_id: ReqData<u64>) -> Result<Json<SuccessResponse<()>>, ErrorResponse> {
+    crate::services::avatar::delete_avatar(app_data.storage.as_ref(), *user_id).await
+        .map_err(|e| ErrorResponse::InternalServerError(e.to_string()))?;
 
     Ok(Json(SuccessResponse::new(())))
 }
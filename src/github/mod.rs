@@ -1 +1,2 @@
 pub mod worker;
+pub mod providers;
@@ -0,0 +1,270 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::Deserialize;
+
+use super::{parse_owner_repo, ExternalIssue, ExternalIssueComment, ExternalIssueState, IssueProvider};
+
+const PAGE_SIZE: u32 = 50;
+
+#[derive(Deserialize)]
+struct GiteaIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: Option<String>,
+    updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize)]
+struct GiteaUser {
+    login: String,
+    html_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GiteaComment {
+    id: u64,
+    body: String,
+    user: GiteaUser,
+    html_url: Option<String>,
+    created_at: DateTime<FixedOffset>,
+    updated_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<GiteaComment> for ExternalIssueComment {
+    fn from(comment: GiteaComment) -> Self {
+        let created_at = comment.created_at.with_timezone(&Utc);
+        ExternalIssueComment {
+            id: comment.id.to_string(),
+            author: comment.user.login,
+            author_url: comment.user.html_url,
+            body: comment.body,
+            url: comment.html_url,
+            created_at,
+            updated_at: comment.updated_at.map(|date| date.with_timezone(&Utc)).unwrap_or(created_at),
+        }
+    }
+}
+
+pub struct GiteaProvider {
+    http: reqwest::Client,
+    api_url: String,
+    token: Option<String>,
+}
+
+impl GiteaProvider {
+    pub fn new(
+        http: reqwest::Client,
+        repository_id: &str,
+        base_url: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<Self> {
+        // Gitea is always self-hosted, so there is no sensible default instance
+        let base_url = base_url.context("Gitea repositories require a base url")?;
+        let (owner, repo) = parse_owner_repo(repository_id)?;
+        let api_url = format!(
+            "{}/api/v1/repos/{}/{}",
+            base_url,
+            urlencoding::encode(&owner),
+            urlencoding::encode(&repo)
+        );
+
+        Ok(Self {
+            http,
+            api_url,
+            token: token.map(|token| token.to_string()),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self.http.request(method, format!("{}{}", self.api_url, path));
+        match &self.token {
+            Some(token) => request.header("Authorization", format!("token {}", token)),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for GiteaProvider {
+    async fn list_issues(&self) -> Result<Vec<ExternalIssue>> {
+        let mut issues = vec![];
+        let mut page = 1;
+        loop {
+            let batch: Vec<GiteaIssue> = self
+                .request(reqwest::Method::GET, "/issues")
+                .query(&[
+                    ("state", "all".to_string()),
+                    ("type", "issues".to_string()),
+                    ("limit", PAGE_SIZE.to_string()),
+                    ("page", page.to_string()),
+                ])
+                .send()
+                .await
+                .context("Failed to fetch issues")?
+                .error_for_status()
+                .context("Failed to fetch issues")?
+                .json()
+                .await
+                .context("Failed to parse issues")?;
+            let last_page = batch.len() < PAGE_SIZE as usize;
+
+            issues.extend(batch.into_iter().map(|issue| ExternalIssue {
+                number: issue.number,
+                title: issue.title,
+                body: issue.body,
+                state: match issue.state.as_str() {
+                    "closed" => ExternalIssueState::Closed,
+                    _ => ExternalIssueState::Open,
+                },
+                url: issue.html_url,
+                updated_at: issue.updated_at.map(|date| date.with_timezone(&Utc)),
+            }));
+
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(issues)
+    }
+
+    async fn set_issue_state(&self, number: u64, state: ExternalIssueState) -> Result<()> {
+        let state = match state {
+            ExternalIssueState::Open => "open",
+            ExternalIssueState::Closed => "closed",
+        };
+        self.request(reqwest::Method::PATCH, &format!("/issues/{}", number))
+            .json(&serde_json::json!({ "state": state }))
+            .send()
+            .await
+            .context("Failed to update issue state")?
+            .error_for_status()
+            .context("Failed to update issue state")?;
+        Ok(())
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<ExternalIssueComment>> {
+        // Gitea returns every comment of an issue at once
+        let comments: Vec<GiteaComment> = self
+            .request(reqwest::Method::GET, &format!("/issues/{}/comments", number))
+            .send()
+            .await
+            .context("Failed to fetch issue comments")?
+            .error_for_status()
+            .context("Failed to fetch issue comments")?
+            .json()
+            .await
+            .context("Failed to parse issue comments")?;
+        Ok(comments.into_iter().map(ExternalIssueComment::from).collect())
+    }
+
+    async fn comment(&self, number: u64, body: &str) -> Result<ExternalIssueComment> {
+        let comment: GiteaComment = self.request(reqwest::Method::POST, &format!("/issues/{}/comments", number))
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .context("Failed to create issue comment")?
+            .error_for_status()
+            .context("Failed to create issue comment")?
+            .json()
+            .await
+            .context("Failed to parse issue comment")?;
+        Ok(comment.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use actix_web::{web, HttpRequest, HttpResponse};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::github::providers::stand_in::serve;
+
+    fn issue(number: u64, state: &str) -> Value {
+        json!({
+            "number": number,
+            "title": format!("Issue {number}"),
+            "body": "",
+            "state": state,
+            "html_url": format!("https://gitea.example/owner/repo/issues/{number}"),
+            "updated_at": "2024-11-01T10:00:00+03:00",
+        })
+    }
+
+    async fn list_issues(req: HttpRequest, query: web::Query<HashMap<String, String>>) -> HttpResponse {
+        if req.headers().get("Authorization").and_then(|token| token.to_str().ok()) != Some("token secret") {
+            return HttpResponse::Unauthorized().json(json!({ "message": "token is required" }));
+        }
+        assert_eq!(query.get("state").map(String::as_str), Some("all"));
+        assert_eq!(query.get("type").map(String::as_str), Some("issues"));
+        assert_eq!(query.get("limit").map(String::as_str), Some("50"));
+        match query.get("page").map(String::as_str) {
+            Some("1") => HttpResponse::Ok().json(
+                (1..=PAGE_SIZE as u64)
+                    .map(|number| issue(number, if number % 2 == 0 { "closed" } else { "open" }))
+                    .collect::<Vec<_>>(),
+            ),
+            Some("2") => HttpResponse::Ok().json(vec![issue(PAGE_SIZE as u64 + 1, "closed")]),
+            _ => HttpResponse::Ok().json(Vec::<Value>::new()),
+        }
+    }
+
+    async fn update_issue(path: web::Path<(String, String, u64)>, body: web::Json<Value>) -> HttpResponse {
+        let (_, _, number) = path.into_inner();
+        match number {
+            1 => HttpResponse::Created().json(issue(1, body["state"].as_str().unwrap_or_default())),
+            _ => HttpResponse::NotFound().json(json!({ "message": "issue does not exist" })),
+        }
+    }
+
+    fn routes(config: &mut web::ServiceConfig) {
+        config
+            .route("/api/v1/repos/{owner}/{repo}/issues", web::get().to(list_issues))
+            .route("/api/v1/repos/{owner}/{repo}/issues/{number}", web::patch().to(update_issue));
+    }
+
+    fn provider(base_url: &str, token: Option<&str>) -> GiteaProvider {
+        GiteaProvider::new(reqwest::Client::new(), "owner/repo", Some(base_url), token).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn lists_issues_of_every_page() {
+        let base_url = serve(routes);
+        let issues = provider(&base_url, Some("secret")).list_issues().await.unwrap();
+
+        assert_eq!(issues.len(), PAGE_SIZE as usize + 1);
+        assert_eq!(issues[0].number, 1);
+        assert_eq!(issues[0].state, ExternalIssueState::Open);
+        assert_eq!(issues[1].state, ExternalIssueState::Closed);
+        assert_eq!(issues[PAGE_SIZE as usize].number, PAGE_SIZE as u64 + 1);
+        assert_eq!(issues[PAGE_SIZE as usize].state, ExternalIssueState::Closed);
+    }
+
+    #[actix_web::test]
+    async fn updates_issue_state() {
+        let base_url = serve(routes);
+        let provider = provider(&base_url, Some("secret"));
+
+        assert!(provider.set_issue_state(1, ExternalIssueState::Closed).await.is_ok());
+        assert!(provider.set_issue_state(2, ExternalIssueState::Closed).await.is_err());
+    }
+
+    #[actix_web::test]
+    async fn reports_failed_requests() {
+        let base_url = serve(routes);
+
+        assert!(provider(&base_url, None).list_issues().await.is_err());
+        assert!(provider(&base_url, Some("secret")).list_comments(1).await.is_err());
+    }
+
+    #[test]
+    fn requires_base_url() {
+        assert!(GiteaProvider::new(reqwest::Client::new(), "owner/repo", None, None).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use jsonwebtoken::EncodingKey;
+use octocrab::{
+    models::{issues::Comment, IssueState},
+    params, Octocrab,
+};
+
+use super::{parse_owner_repo, ExternalIssue, ExternalIssueComment, ExternalIssueState, IssueProvider};
+
+pub struct GitHubProvider {
+    octocrab: Octocrab,
+    owner: String,
+    repo: String,
+}
+
+impl GitHubProvider {
+    pub fn new(
+        app_id: u64,
+        app_key: EncodingKey,
+        repository_id: &str,
+        base_url: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<Self> {
+        let (owner, repo) = parse_owner_repo(repository_id)?;
+
+        let builder = match (token, base_url) {
+            (Some(token), Some(base_url)) => Octocrab::builder()
+                .personal_token(token.to_string())
+                .base_uri(base_url)
+                .context("Invalid GitHub base url")?,
+            (Some(token), None) => Octocrab::builder().personal_token(token.to_string()),
+            // The app credentials are only ever sent to github.com
+            (None, None) => Octocrab::builder().app(app_id.into(), app_key),
+            (None, Some(_)) => anyhow::bail!("GitHub Enterprise repositories require an access token"),
+        };
+        let octocrab = builder.build().context("Failed to build Octocrab instance")?;
+
+        Ok(Self { octocrab, owner, repo })
+    }
+}
+
+fn comment_to_external(comment: Comment) -> ExternalIssueComment {
+    ExternalIssueComment {
+        id: comment.id.to_string(),
+        author: comment.user.login,
+        author_url: Some(comment.user.html_url.to_string()),
+        body: comment.body.unwrap_or_default(),
+        url: Some(comment.html_url.to_string()),
+        created_at: comment.created_at,
+        updated_at: comment.updated_at.unwrap_or(comment.created_at),
+    }
+}
+
+#[async_trait]
+impl IssueProvider for GitHubProvider {
+    async fn list_issues(&self) -> Result<Vec<ExternalIssue>> {
+        let page = self.octocrab
+            .issues(&self.owner, &self.repo)
+            .list()
+            .state(params::State::All)
+            .per_page(100)
+            .send()
+            .await
+            .context("Failed to fetch issues")?;
+        let issues = self.octocrab.all_pages(page).await.context("Failed to fetch issues")?;
+
+        Ok(issues
+            .into_iter()
+            // GitHub lists pull requests as issues too
+            .filter(|issue| issue.pull_request.is_none())
+            .map(|issue| ExternalIssue {
+                number: issue.number,
+                title: issue.title,
+                body: issue.body,
+                state: match issue.state {
+                    IssueState::Closed => ExternalIssueState::Closed,
+                    _ => ExternalIssueState::Open,
+                },
+                url: Some(issue.html_url.to_string()),
+                updated_at: Some(issue.updated_at),
+            })
+            .collect())
+    }
+
+    async fn set_issue_state(&self, number: u64, state: ExternalIssueState) -> Result<()> {
+        let state = match state {
+            ExternalIssueState::Open => IssueState::Open,
+            ExternalIssueState::Closed => IssueState::Closed,
+        };
+        self.octocrab
+            .issues(&self.owner, &self.repo)
+            .update(number)
+            .state(state)
+            .send()
+            .await
+            .context("Failed to update issue state")?;
+        Ok(())
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<ExternalIssueComment>> {
+        let page = self.octocrab
+            .issues(&self.owner, &self.repo)
+            .list_comments(number)
+            .per_page(100)
+            .send()
+            .await
+            .context("Failed to fetch issue comments")?;
+        let comments = self.octocrab.all_pages(page).await.context("Failed to fetch issue comments")?;
+
+        Ok(comments.into_iter().map(comment_to_external).collect())
+    }
+
+    async fn comment(&self, number: u64, body: &str) -> Result<ExternalIssueComment> {
+        let comment = self.octocrab
+            .issues(&self.owner, &self.repo)
+            .create_comment(number, body)
+            .await
+            .context("Failed to create issue comment")?;
+        Ok(comment_to_external(comment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use actix_web::{web, HttpRequest, HttpResponse};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::github::providers::stand_in::{base_url, serve};
+
+    fn user(base_url: &str) -> Value {
+        let url = |path: &str| format!("{base_url}/{path}");
+        json!({
+            "login": "octocat",
+            "id": 1,
+            "node_id": "U_1",
+            "avatar_url": url("avatars/octocat"),
+            "gravatar_id": "",
+            "url": url("users/octocat"),
+            "html_url": url("octocat"),
+            "followers_url": url("users/octocat/followers"),
+            "following_url": url("users/octocat/following"),
+            "gists_url": url("users/octocat/gists"),
+            "starred_url": url("users/octocat/starred"),
+            "subscriptions_url": url("users/octocat/subscriptions"),
+            "organizations_url": url("users/octocat/orgs"),
+            "repos_url": url("users/octocat/repos"),
+            "events_url": url("users/octocat/events"),
+            "received_events_url": url("users/octocat/received_events"),
+            "type": "User",
+            "site_admin": false,
+        })
+    }
+
+    fn issue(base_url: &str, number: u64, state: &str, pull_request: bool) -> Value {
+        let url = |path: &str| format!("{base_url}/repos/owner/repo/{path}");
+        let mut issue = json!({
+            "id": number,
+            "node_id": format!("I_{number}"),
+            "url": url(&format!("issues/{number}")),
+            "repository_url": format!("{base_url}/repos/owner/repo"),
+            "labels_url": url(&format!("issues/{number}/labels")),
+            "comments_url": url(&format!("issues/{number}/comments")),
+            "events_url": url(&format!("issues/{number}/events")),
+            "html_url": format!("{base_url}/owner/repo/issues/{number}"),
+            "number": number,
+            "state": state,
+            "title": format!("Issue {number}"),
+            "body": null,
+            "user": user(base_url),
+            "labels": [],
+            "assignees": [],
+            "author_association": "OWNER",
+            "locked": false,
+            "comments": 0,
+            "created_at": "2024-11-01T10:00:00Z",
+            "updated_at": "2024-11-02T10:00:00Z",
+        });
+        if pull_request {
+            issue["pull_request"] = json!({
+                "url": url(&format!("pulls/{number}")),
+                "html_url": format!("{base_url}/owner/repo/pull/{number}"),
+                "diff_url": format!("{base_url}/owner/repo/pull/{number}.diff"),
+                "patch_url": format!("{base_url}/owner/repo/pull/{number}.patch"),
+            });
+        }
+        issue
+    }
+
+    async fn list_issues(req: HttpRequest, query: web::Query<HashMap<String, String>>) -> HttpResponse {
+        assert_eq!(query.get("state").map(String::as_str), Some("all"));
+        let base_url = base_url(&req);
+        match query.get("page").map(String::as_str) {
+            None | Some("1") => HttpResponse::Ok()
+                .insert_header((
+                    "Link",
+                    format!(r#"<{base_url}/repos/owner/repo/issues?state=all&per_page=100&page=2>; rel="next""#),
+                ))
+                .json(vec![issue(&base_url, 1, "open", false), issue(&base_url, 2, "open", true)]),
+            _ => HttpResponse::Ok().json(vec![issue(&base_url, 3, "closed", false)]),
+        }
+    }
+
+    fn routes(config: &mut web::ServiceConfig) {
+        config
+            .route("/repos/owner/repo/issues", web::get().to(list_issues))
+            .route(
+                "/repos/owner/repo/issues/{number}",
+                web::patch().to(|| async {
+                    HttpResponse::InternalServerError().json(json!({
+                        "message": "Server Error",
+                        "documentation_url": "https://docs.github.com/rest",
+                    }))
+                }),
+            );
+    }
+
+    fn provider(base_url: &str) -> GitHubProvider {
+        GitHubProvider::new(1, EncodingKey::from_secret(b"unused"), "owner/repo", Some(base_url), Some("secret")).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn lists_issues_of_every_page_without_pull_requests() {
+        let base_url = serve(routes);
+        let issues = provider(&base_url).list_issues().await.unwrap();
+
+        let numbers: Vec<u64> = issues.iter().map(|issue| issue.number).collect();
+        assert_eq!(numbers, vec![1, 3]);
+        assert_eq!(issues[0].state, ExternalIssueState::Open);
+        assert_eq!(issues[1].state, ExternalIssueState::Closed);
+    }
+
+    #[actix_web::test]
+    async fn reports_failed_requests() {
+        let base_url = serve(routes);
+
+        assert!(provider(&base_url).set_issue_state(1, ExternalIssueState::Closed).await.is_err());
+        assert!(provider(&base_url).list_comments(1).await.is_err());
+    }
+
+    #[test]
+    fn requires_token_for_enterprise_hosts() {
+        let key = EncodingKey::from_secret(b"unused");
+        assert!(GitHubProvider::new(1, key, "owner/repo", Some("https://github.example.com/api/v3"), None).is_err());
+    }
+
+    #[test]
+    fn rejects_repository_without_owner() {
+        assert!(parse_owner_repo("repo").is_err());
+        assert!(parse_owner_repo("owner/repo/extra").is_err());
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::Deserialize;
+
+use super::{ExternalIssue, ExternalIssueComment, ExternalIssueState, IssueProvider};
+
+const DEFAULT_BASE_URL: &str = "https://gitlab.com";
+const PAGE_SIZE: u32 = 100;
+
+#[derive(Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    web_url: Option<String>,
+    updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize)]
+struct GitLabUser {
+    username: String,
+    web_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitLabNote {
+    id: u64,
+    body: String,
+    author: GitLabUser,
+    created_at: DateTime<FixedOffset>,
+    updated_at: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    system: bool,
+}
+
+impl From<GitLabNote> for ExternalIssueComment {
+    fn from(note: GitLabNote) -> Self {
+        let created_at = note.created_at.with_timezone(&Utc);
+        ExternalIssueComment {
+            id: note.id.to_string(),
+            author: note.author.username,
+            author_url: note.author.web_url,
+            body: note.body,
+            url: None,
+            created_at,
+            updated_at: note.updated_at.map(|date| date.with_timezone(&Utc)).unwrap_or(created_at),
+        }
+    }
+}
+
+pub struct GitLabProvider {
+    http: reqwest::Client,
+    api_url: String,
+    token: Option<String>,
+}
+
+impl GitLabProvider {
+    pub fn new(
+        http: reqwest::Client,
+        repository_id: &str,
+        base_url: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<Self> {
+        // GitLab projects can be nested into subgroups, so the whole path is the project id
+        let path = repository_id.trim_matches('/');
+        if !path.contains('/') {
+            anyhow::bail!("Invalid repository: expected group/project");
+        }
+        let api_url = format!(
+            "{}/api/v4/projects/{}",
+            base_url.unwrap_or(DEFAULT_BASE_URL),
+            urlencoding::encode(path)
+        );
+
+        Ok(Self {
+            http,
+            api_url,
+            token: token.map(|token| token.to_string()),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self.http.request(method, format!("{}{}", self.api_url, path));
+        match &self.token {
+            Some(token) => request.header("PRIVATE-TOKEN", token),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for GitLabProvider {
+    async fn list_issues(&self) -> Result<Vec<ExternalIssue>> {
+        let mut issues = vec![];
+        let mut page = 1;
+        loop {
+            let batch: Vec<GitLabIssue> = self
+                .request(reqwest::Method::GET, "/issues")
+                .query(&[("state", "all".to_string()), ("per_page", PAGE_SIZE.to_string()), ("page", page.to_string())])
+                .send()
+                .await
+                .context("Failed to fetch issues")?
+                .error_for_status()
+                .context("Failed to fetch issues")?
+                .json()
+                .await
+                .context("Failed to parse issues")?;
+            let last_page = batch.len() < PAGE_SIZE as usize;
+
+            issues.extend(batch.into_iter().map(|issue| ExternalIssue {
+                number: issue.iid,
+                title: issue.title,
+                body: issue.description,
+                state: match issue.state.as_str() {
+                    "closed" => ExternalIssueState::Closed,
+                    _ => ExternalIssueState::Open,
+                },
+                url: issue.web_url,
+                updated_at: issue.updated_at.map(|date| date.with_timezone(&Utc)),
+            }));
+
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(issues)
+    }
+
+    async fn set_issue_state(&self, number: u64, state: ExternalIssueState) -> Result<()> {
+        let state_event = match state {
+            ExternalIssueState::Open => "reopen",
+            ExternalIssueState::Closed => "close",
+        };
+        self.request(reqwest::Method::PUT, &format!("/issues/{}", number))
+            .query(&[("state_event", state_event)])
+            .send()
+            .await
+            .context("Failed to update issue state")?
+            .error_for_status()
+            .context("Failed to update issue state")?;
+        Ok(())
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<ExternalIssueComment>> {
+        let mut comments = vec![];
+        let mut page = 1;
+        loop {
+            let batch: Vec<GitLabNote> = self
+                .request(reqwest::Method::GET, &format!("/issues/{}/notes", number))
+                .query(&[
+                    ("sort", "asc".to_string()),
+                    ("per_page", PAGE_SIZE.to_string()),
+                    ("page", page.to_string()),
+                ])
+                .send()
+                .await
+                .context("Failed to fetch issue comments")?
+                .error_for_status()
+                .context("Failed to fetch issue comments")?
+                .json()
+                .await
+                .context("Failed to parse issue comments")?;
+            let last_page = batch.len() < PAGE_SIZE as usize;
+
+            // System notes record events like label changes, not discussion
+            comments.extend(batch.into_iter().filter(|note| !note.system).map(ExternalIssueComment::from));
+
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(comments)
+    }
+
+    async fn comment(&self, number: u64, body: &str) -> Result<ExternalIssueComment> {
+        let note: GitLabNote = self.request(reqwest::Method::POST, &format!("/issues/{}/notes", number))
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .context("Failed to create issue comment")?
+            .error_for_status()
+            .context("Failed to create issue comment")?
+            .json()
+            .await
+            .context("Failed to parse issue comment")?;
+        Ok(note.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use actix_web::{web, HttpRequest, HttpResponse};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::github::providers::stand_in::serve;
+
+    fn issue(iid: u64, state: &str) -> Value {
+        json!({
+            "iid": iid,
+            "title": format!("Issue {iid}"),
+            "description": null,
+            "state": state,
+            "web_url": format!("https://gitlab.example/group/project/-/issues/{iid}"),
+            "updated_at": "2024-11-01T10:00:00.000Z",
+        })
+    }
+
+    fn note(id: u64, system: bool) -> Value {
+        json!({
+            "id": id,
+            "body": format!("Note {id}"),
+            "author": { "username": "reviewer", "web_url": null },
+            "created_at": "2024-11-01T10:00:00.000Z",
+            "updated_at": null,
+            "system": system,
+        })
+    }
+
+    async fn list_issues(req: HttpRequest, query: web::Query<HashMap<String, String>>) -> HttpResponse {
+        if req.headers().get("PRIVATE-TOKEN").and_then(|token| token.to_str().ok()) != Some("secret") {
+            return HttpResponse::Unauthorized().json(json!({ "message": "401 Unauthorized" }));
+        }
+        assert_eq!(query.get("state").map(String::as_str), Some("all"));
+        assert_eq!(query.get("per_page").map(String::as_str), Some("100"));
+        match query.get("page").map(String::as_str) {
+            Some("1") => HttpResponse::Ok().json(
+                (1..=PAGE_SIZE as u64)
+                    .map(|iid| issue(iid, if iid % 2 == 0 { "closed" } else { "opened" }))
+                    .collect::<Vec<_>>(),
+            ),
+            Some("2") => HttpResponse::Ok().json(vec![issue(PAGE_SIZE as u64 + 1, "closed")]),
+            _ => HttpResponse::Ok().json(Vec::<Value>::new()),
+        }
+    }
+
+    async fn list_notes(query: web::Query<HashMap<String, String>>) -> HttpResponse {
+        match query.get("page").map(String::as_str) {
+            Some("1") => HttpResponse::Ok().json(vec![note(1, false), note(2, true), note(3, false)]),
+            _ => HttpResponse::Ok().json(Vec::<Value>::new()),
+        }
+    }
+
+    fn routes(config: &mut web::ServiceConfig) {
+        config
+            .route("/api/v4/projects/{project}/issues", web::get().to(list_issues))
+            .route("/api/v4/projects/{project}/issues/{number}/notes", web::get().to(list_notes))
+            .route("/api/v4/projects/{project}/issues/{number}", web::put().to(|| async { HttpResponse::Forbidden().finish() }));
+    }
+
+    fn provider(base_url: &str, token: Option<&str>) -> GitLabProvider {
+        GitLabProvider::new(reqwest::Client::new(), "group/project", Some(base_url), token).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn lists_issues_of_every_page() {
+        let base_url = serve(routes);
+        let issues = provider(&base_url, Some("secret")).list_issues().await.unwrap();
+
+        assert_eq!(issues.len(), PAGE_SIZE as usize + 1);
+        assert_eq!(issues[0].number, 1);
+        assert_eq!(issues[0].state, ExternalIssueState::Open);
+        assert_eq!(issues[1].state, ExternalIssueState::Closed);
+        assert_eq!(issues[PAGE_SIZE as usize].number, PAGE_SIZE as u64 + 1);
+        assert_eq!(issues[PAGE_SIZE as usize].state, ExternalIssueState::Closed);
+    }
+
+    #[actix_web::test]
+    async fn skips_system_notes() {
+        let base_url = serve(routes);
+        let comments = provider(&base_url, Some("secret")).list_comments(1).await.unwrap();
+
+        let ids: Vec<&str> = comments.iter().map(|comment| comment.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "3"]);
+    }
+
+    #[actix_web::test]
+    async fn reports_failed_requests() {
+        let base_url = serve(routes);
+
+        assert!(provider(&base_url, None).list_issues().await.is_err());
+        assert!(provider(&base_url, Some("secret")).set_issue_state(1, ExternalIssueState::Closed).await.is_err());
+    }
+
+    #[test]
+    fn rejects_repository_without_group() {
+        assert!(GitLabProvider::new(reqwest::Client::new(), "project", None, None).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::EncodingKey;
+
+use crate::models::project::{RepositoryLink, RepositoryProvider};
+
+pub mod github;
+pub mod gitlab;
+pub mod gitea;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternalIssueState {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: ExternalIssueState,
+    pub url: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalIssueComment {
+    pub id: String,
+    pub author: String,
+    pub author_url: Option<String>,
+    pub body: String,
+    pub url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Issue tracker of a single repository linked to a project.
+#[async_trait]
+pub trait IssueProvider: Send + Sync {
+    async fn list_issues(&self) -> Result<Vec<ExternalIssue>>;
+    async fn set_issue_state(&self, number: u64, state: ExternalIssueState) -> Result<()>;
+    async fn list_comments(&self, number: u64) -> Result<Vec<ExternalIssueComment>>;
+    async fn comment(&self, number: u64, body: &str) -> Result<ExternalIssueComment>;
+}
+
+/// Builds an `IssueProvider` for a project's repository link.
+#[derive(Clone)]
+pub struct IssueProviders {
+    github_app_id: u64,
+    github_app_key: EncodingKey,
+    http: reqwest::Client,
+}
+
+impl IssueProviders {
+    pub fn new(github_app_id: u64, github_app_private_key: &str) -> Result<Self> {
+        let github_app_key = EncodingKey::from_rsa_pem(github_app_private_key.as_bytes())
+            .context("Failed to create encoding key")?;
+
+        Ok(Self {
+            github_app_id,
+            github_app_key,
+            http: reqwest::Client::new(),
+        })
+    }
+
+    pub fn for_repository(&self, link: &RepositoryLink) -> Result<Box<dyn IssueProvider>> {
+        let base_url = link.base_url.as_deref().map(|url| url.trim_end_matches('/'));
+        Ok(match link.provider {
+            RepositoryProvider::GitHub => Box::new(github::GitHubProvider::new(
+                self.github_app_id,
+                self.github_app_key.clone(),
+                &link.repository_id,
+                base_url,
+                link.token.as_deref(),
+            )?),
+            RepositoryProvider::GitLab => Box::new(gitlab::GitLabProvider::new(
+                self.http.clone(),
+                &link.repository_id,
+                base_url,
+                link.token.as_deref(),
+            )?),
+            RepositoryProvider::Gitea => Box::new(gitea::GiteaProvider::new(
+                self.http.clone(),
+                &link.repository_id,
+                base_url,
+                link.token.as_deref(),
+            )?),
+        })
+    }
+}
+
+/// Splits an `owner/repo` repository id.
+pub fn parse_owner_repo(repository_id: &str) -> Result<(String, String)> {
+    let (owner, repo) = repository_id.split_once('/').context("Invalid repository format")?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        anyhow::bail!("Invalid repository: expected owner/repo");
+    }
+    Ok((owner.to_string(), repo.to_string()))
+}
+
+/// Local HTTP server standing in for an issue tracker in provider tests.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use actix_web::{web::ServiceConfig, App, HttpRequest, HttpServer};
+
+    /// Serves `routes` on a free local port and returns the base url of the server.
+    pub(crate) fn serve(routes: fn(&mut ServiceConfig)) -> String {
+        let server = HttpServer::new(move || App::new().configure(routes))
+            .workers(1)
+            .disable_signals()
+            .bind(("127.0.0.1", 0))
+            .expect("Failed to bind the stand-in server");
+        let address = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+        format!("http://{}", address)
+    }
+
+    /// Base url of the stand-in serving `req`, for links pointing back to it.
+    pub(crate) fn base_url(req: &HttpRequest) -> String {
+        let info = req.connection_info();
+        format!("{}://{}", info.scheme(), info.host())
+    }
+}
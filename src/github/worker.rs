@@ -1,93 +1,294 @@
-use anyhow::{Context, Result};
-use jsonwebtoken::EncodingKey;
-use octocrab::{
-    models::{issues::Issue, IssueState},
-    Octocrab,
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
 };
-use tokio::time::{sleep, Duration};
+
+use anyhow::Result;
+use tokio::sync::Notify;
+use tokio::time::{interval, sleep_until, Duration, Instant};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
+    github::providers::{ExternalIssue, ExternalIssueState, IssueProvider, IssueProviders},
+    mailer::mailer::Mailer,
     models::{
+        actor::{Actor, SystemActor},
         project::SelectProject,
-        task::{CreateTaskRequest, SelectTask, TaskStatus, UpdateTaskRequest},
+        task::{CreateTaskRequest, SelectTask, UpdateTaskRequest},
+        workflow::StatusCategory,
     },
     services::{
-        project::get_all_projects,
-        task::{create_task, update_task},
+        dependency::{get_blocked_task_ids, propagate_status_change},
+        discussion::{get_linked_tasks, store_external_comments},
+        notifications::create_notification,
+        lease::{hold_lease, keep_lease, release_lease},
+        project::{get_all_projects, get_repository_link},
+        sync::{get_requested_sync_project_ids, record_sync_failure, record_sync_success},
+        task::{create_task, get_task_by_issue, update_task},
+        workflow::{first_status_in_category, get_project_statuses},
     },
+    utils::secret::SecretKey,
 };
 
+const LOG_TAG: &'static str = "GitHubWorker";
+/// Number of failed syncs in a row after which the project owner is notified.
+const FAILURE_NOTIFICATION_THRESHOLD: u64 = 3;
+/// Name of the lease that makes sure only one replica syncs repositories.
+const LEASE_NAME: &'static str = "github_worker";
+/// How often the lease holder checks for manually requested syncs, which may come from any replica.
+const REQUEST_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Comments are refetched at least this often, as editing a comment does not always touch the issue.
+const COMMENT_RESYNC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Default)]
+struct SyncOutcome {
+    items_created: u64,
+    items_updated: u64,
+}
+
+enum IssueOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
 pub struct GitHubWorker {
-    octocrab: Octocrab,
+    providers: IssueProviders,
+    token_key: SecretKey,
+    mailer: Mailer,
     cancel_token: CancellationToken,
+    sync_trigger: Arc<Notify>,
     process_interval: Duration,
+    is_leader: AtomicBool,
 }
 
 impl GitHubWorker {
-    pub async fn new(
-        app_id: u64,
-        private_key: &str,
+    pub fn new(
+        providers: IssueProviders,
+        token_key: SecretKey,
+        mailer: Mailer,
         cancel_token: CancellationToken,
+        sync_trigger: Arc<Notify>,
         process_interval: Duration,
-    ) -> Result<Self> {
-        let access_key = EncodingKey::from_rsa_pem(private_key.as_bytes())
-            .context("Failed to create encoding key")?;
-        let octocrab = Octocrab::builder()
-            .app(app_id.into(), access_key)
-            .build()
-            .context("Failed to build Octocrab instance")?;
-
-        Ok(Self {
-            octocrab,
+    ) -> Self {
+        Self {
+            providers,
+            token_key,
+            mailer,
             cancel_token,
+            sync_trigger,
             process_interval,
-        })
+            is_leader: AtomicBool::new(false),
+        }
     }
 
     pub async fn work(&self) -> Result<()> {
-        log::info!("GitHub worker started");
-        while !self.cancel_token.is_cancelled() {
-            if let Err(e) = self.process_projects().await {
-                log::error!("Error processing projects: {}", e);
-            }
-
+        log::info!(target: LOG_TAG, "Repository sync worker started");
+        let mut next_run = Instant::now();
+        let mut request_poll = interval(REQUEST_POLL_INTERVAL);
+        loop {
             tokio::select! {
-                _ = sleep(self.process_interval) => {}
+                _ = sleep_until(next_run) => {
+                    if hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                        self.process_projects().await;
+                    }
+                    next_run = Instant::now() + self.process_interval;
+                }
+                _ = self.sync_trigger.notified() => {
+                    if hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                        self.process_requested_projects().await;
+                    }
+                }
+                _ = request_poll.tick() => {
+                    if hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                        self.process_requested_projects().await;
+                    }
+                }
                 _ = self.cancel_token.cancelled() => {
-                    log::info!("Graceful shutdown triggered");
+                    log::info!(target: LOG_TAG, "Graceful shutdown triggered");
+                    if self.is_leader.load(Ordering::Relaxed) {
+                        release_lease(LEASE_NAME).await.ok();
+                    }
                     break;
                 }
             }
         }
         Ok(())
     }
-    async fn process_projects(&self) -> Result<()> {
-        let projects = get_all_projects().await.map_err(|e| anyhow::anyhow!("Failed to get projects: {}", e))?;
+
+    async fn process_projects(&self) {
+        let projects = match get_all_projects().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                log::error!(target: LOG_TAG, "Failed to get projects: {}", e);
+                return;
+            }
+        };
 
         for project in &projects {
             if project.repository_id.is_none() {
                 continue;
             }
+            if !hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                return;
+            }
+            self.sync_project(project).await;
+        }
+    }
+
+    async fn process_requested_projects(&self) {
+        let requested = match get_requested_sync_project_ids().await {
+            Ok(requested) => requested,
+            Err(e) => {
+                log::error!(target: LOG_TAG, "Failed to get requested syncs: {}", e);
+                return;
+            }
+        };
+        if requested.is_empty() {
+            return;
+        }
 
-            let (owner, repo) = self.parse_repository(project)?;
-            let issues = self.fetch_issues(&owner, &repo).await?;
+        let projects = match get_all_projects().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                log::error!(target: LOG_TAG, "Failed to get projects: {}", e);
+                return;
+            }
+        };
 
-            for issue in issues {
-                self.handle_issue(project, &issue).await?;
+        for project in projects.iter().filter(|project| requested.contains(&project.id)) {
+            if project.repository_id.is_none() {
+                continue;
             }
+            if !hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                return;
+            }
+            self.sync_project(project).await;
         }
-        Ok(())
     }
 
-    async fn handle_issue(&self, project: &SelectProject, issue: &Issue) -> Result<()> {
+    /// Syncs a single project and persists the outcome, so one broken repository
+    /// does not stop the others from being processed.
+    async fn sync_project(&self, project: &SelectProject) {
+        let started_at = chrono::Utc::now();
+        let started = Instant::now();
+        let result = tokio::select! {
+            result = self.process_project(project) => result,
+            _ = keep_lease(LEASE_NAME, self.process_interval, &self.is_leader) => {
+                // The new holder syncs the project again, so the interrupted sync is not recorded
+                log::warn!(target: LOG_TAG, "Lost the worker lease while syncing project {}", project.id);
+                return;
+            }
+        };
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(outcome) => {
+                if let Err(e) = record_sync_success(
+                    project.id,
+                    started_at,
+                    outcome.items_created,
+                    outcome.items_updated,
+                    duration_ms,
+                ).await {
+                    log::error!(target: LOG_TAG, "Failed to record sync of project {}: {}", project.id, e);
+                }
+            }
+            Err(err) => {
+                let error = format!("{:#}", err);
+                log::error!(target: LOG_TAG, "Failed to sync project {}: {}", project.id, error);
+                match record_sync_failure(project.id, started_at, &error, duration_ms).await {
+                    Ok(failures) if failures == FAILURE_NOTIFICATION_THRESHOLD => {
+                        self.notify_sync_failure(project, failures, &error).await;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        log::error!(target: LOG_TAG, "Failed to record sync of project {}: {}", project.id, e);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn process_project(&self, project: &SelectProject) -> Result<SyncOutcome> {
+        let link = get_repository_link(&self.token_key, project.id).await
+            .map_err(|e| anyhow::anyhow!("Failed to get repository link: {}", e))?
+            .ok_or_else(|| anyhow::anyhow!("Project has no repository_id"))?;
+        let provider = self.providers.for_repository(&link)?;
+        let issues = provider.list_issues().await?;
+
+        let mut outcome = SyncOutcome::default();
+        for issue in &issues {
+            match self.handle_issue(project, issue).await? {
+                IssueOutcome::Created => outcome.items_created += 1,
+                IssueOutcome::Updated => outcome.items_updated += 1,
+                IssueOutcome::Unchanged => {}
+            }
+        }
+
+        let (comments_created, comments_updated) = self.sync_comments(project, provider.as_ref(), &issues).await?;
+        outcome.items_created += comments_created;
+        outcome.items_updated += comments_updated;
+        Ok(outcome)
+    }
+
+    /// Mirrors the comments of every linked issue that changed since its comments were last fetched.
+    async fn sync_comments(
+        &self,
+        project: &SelectProject,
+        provider: &dyn IssueProvider,
+        issues: &[ExternalIssue],
+    ) -> Result<(u64, u64)> {
+        let linked_tasks = get_linked_tasks(project.id).await
+            .map_err(|e| anyhow::anyhow!("Failed to get linked tasks: {}", e))?;
+        let now = chrono::Utc::now();
+
+        let mut created = 0;
+        let mut updated = 0;
+        for issue in issues {
+            let Some(task) = linked_tasks.get(&issue.number) else {
+                continue;
+            };
+            let outdated = match (task.comments_synced_at, issue.updated_at) {
+                (Some(synced_at), _) if (now - synced_at).num_seconds() > COMMENT_RESYNC_INTERVAL.as_secs() as i64 => true,
+                (Some(synced_at), Some(updated_at)) => updated_at > synced_at,
+                _ => true,
+            };
+            if !outdated {
+                continue;
+            }
+
+            let comments = provider.list_comments(issue.number).await?;
+            let (comments_created, comments_updated) = store_external_comments(task.task_id, &comments).await
+                .map_err(|e| anyhow::anyhow!("Failed to store comments of issue #{}: {}", issue.number, e))?;
+            created += comments_created;
+            updated += comments_updated;
+        }
+        Ok((created, updated))
+    }
+
+    async fn notify_sync_failure(&self, project: &SelectProject, failures: u64, error: &str) {
+        create_notification(
+            "Ошибка синхронизации с репозиторием".to_string(),
+            format!(
+                "Синхронизация проекта {} с репозиторием {} завершилась ошибкой {} раз подряд. Последняя ошибка: {}",
+                project.name,
+                project.repository_id.clone().unwrap_or_default(),
+                failures,
+                error
+            ),
+            project.owner.id,
+            &self.mailer
+        ).await;
+    }
+
+    async fn handle_issue(&self, project: &SelectProject, issue: &ExternalIssue) -> Result<IssueOutcome> {
         match issue.state {
-            IssueState::Open => self.handle_open_issue(project, issue).await,
-            IssueState::Closed => self.handle_closed_issue(project, issue).await,
-            _ => Ok(()),
+            ExternalIssueState::Open => self.handle_open_issue(project, issue).await,
+            ExternalIssueState::Closed => self.handle_closed_issue(project, issue).await,
         }
     }
-    async fn create_task_for_issue(&self, project: &SelectProject, issue: &Issue) -> Result<()> {
+    async fn create_task_for_issue(&self, project: &SelectProject, issue: &ExternalIssue) -> Result<()> {
         let task = CreateTaskRequest {
             name: issue.title.clone(),
             description: issue.body.clone().unwrap_or_default(),
@@ -95,57 +296,80 @@ impl GitHubWorker {
             attached_to: Vec::new(),
             assigned_issue: Some(issue.number),
             due_date: None,
+            parent_id: None,
+            priority: None,
+            estimate: None,
+            label_ids: Vec::new(),
         };
 
-        create_task(project.owner.id, &task).await.map_err(|e| anyhow::anyhow!("Failed to create task: {}", e))?;
+        let actor = SystemActor::repository_sync(project.repository_provider);
+        let task = create_task(Actor::System(actor), &task).await.map_err(|e| anyhow::anyhow!("Failed to create task: {}", e))?;
+
+        create_notification(
+            "Новая задача из репозитория".to_string(),
+            format!("{} создал задачу {} в проекте {} по issue #{}.", actor.display_name(), task.name, project.name, issue.number),
+            project.owner.id,
+            &self.mailer
+        ).await;
         Ok(())
     }
-    async fn close_task_for_issue(&self, project: &SelectProject, task: &SelectTask, issue: &Issue) -> Result<()> {
+    async fn close_task_for_issue(&self, project: &SelectProject, task: &SelectTask, issue: &ExternalIssue) -> Result<()> {
+        let statuses = get_project_statuses(project.id).await
+            .map_err(|e| anyhow::anyhow!("Failed to get project statuses: {}", e))?;
+        let done = first_status_in_category(&statuses, StatusCategory::Done)
+            .ok_or_else(|| anyhow::anyhow!("Workflow of project {} has no done status", project.id))?;
         let updated = UpdateTaskRequest {
             assigned_issue: Some(issue.number),
             name: None,
             description: None,
-            status: Some(TaskStatus::Done),
+            status: Some(done),
             due_date: None,
+            complete_subtasks: None,
+            priority: None,
+            estimate: None,
+            label_ids: None,
         };
 
-        update_task(project.owner.id, task.id, &updated).await.map_err(|e| anyhow::anyhow!("Failed to update task: {}", e))?;
-        Ok(())
-    }
-    fn parse_repository(&self, project: &SelectProject) -> Result<(String, String)> {
-        let repo = project.repository_id.as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Project has no repository_id"))?;
-        let (owner, repo) = repo.split_once('/').context("Invalid repository format")?;
-        if owner.is_empty() || repo.is_empty() {
-            anyhow::bail!("Invalid repository: owner or repo is empty");
-        }
-        Ok((owner.to_string(), repo.to_string()))
-    }
+        let actor = SystemActor::repository_sync(project.repository_provider);
+        update_task(Actor::System(actor), task.id, &updated, None).await.map_err(|e| anyhow::anyhow!("Failed to update task: {}", e))?;
+        let blocked_task_ids = get_blocked_task_ids(task.id).await
+            .map_err(|e| anyhow::anyhow!("Failed to get blocked tasks: {}", e))?;
+        propagate_status_change(&self.mailer, &Actor::System(actor), task.id, &blocked_task_ids).await;
 
-    async fn fetch_issues(&self, owner: &str, repo: &str) -> Result<Vec<Issue>> {
-        Ok(self.octocrab
-            .issues(owner, repo)
-            .list()
-            .send()
-            .await
-            .context("Failed to fetch issues")?
-            .items)
+        for user in &task.attached_to {
+            create_notification(
+                "Задача выполнена".to_string(),
+                format!("{} закрыл задачу {}: issue #{} был закрыт в репозитории.", actor.display_name(), task.name, issue.number),
+                user.id,
+                &self.mailer
+            ).await;
+        }
+        Ok(())
     }
-
-    async fn handle_open_issue(&self, project: &SelectProject, issue: &Issue) -> Result<()> {
+    async fn handle_open_issue(&self, project: &SelectProject, issue: &ExternalIssue) -> Result<IssueOutcome> {
         let task_exists = project.tasks.iter().any(|task| task.assigned_issue == Some(issue.number));
-        if !task_exists {
-            self.create_task_for_issue(project, issue).await?;
+        if task_exists {
+            return Ok(IssueOutcome::Unchanged);
         }
-        Ok(())
+        if let Err(err) = self.create_task_for_issue(project, issue).await {
+            // The (projectId, assignedIssue) constraint rejects a task created concurrently elsewhere
+            let existing = get_task_by_issue(project.id, issue.number).await
+                .map_err(|e| anyhow::anyhow!("Failed to get task: {}", e))?;
+            return match existing {
+                Some(_) => Ok(IssueOutcome::Unchanged),
+                None => Err(err),
+            };
+        }
+        Ok(IssueOutcome::Created)
     }
 
-    async fn handle_closed_issue(&self, project: &SelectProject, issue: &Issue) -> Result<()> {
+    async fn handle_closed_issue(&self, project: &SelectProject, issue: &ExternalIssue) -> Result<IssueOutcome> {
         if let Some(task) = project.tasks.iter().find(|task| task.assigned_issue == Some(issue.number)) {
-            if task.status != TaskStatus::Done {
+            if task.status_category != StatusCategory::Done {
                 self.close_task_for_issue(project, task, issue).await?;
+                return Ok(IssueOutcome::Updated);
             }
         }
-        Ok(())
+        Ok(IssueOutcome::Unchanged)
     }
 }
@@ -1,12 +1,16 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use actix_cors::Cors;
+use actix_multipart::form::MultipartFormConfig;
 use actix_web::{middleware::Logger, App, HttpServer};
 use apistos::{
     app::{BuildConfig, OpenApiWrapper}, web::scope, ScalarConfig
 };
-use github::worker::GitHubWorker;
+use board::listener::BoardEventListener;
+use github::{providers::IssueProviders, worker::GitHubWorker};
 use mailer::mailer::Mailer;
+use recurrence::worker::RecurrenceWorker;
+use tokio::sync::{broadcast, Notify};
 use tokio_util::sync::CancellationToken;
 
 use utils::{app_data::AppData, openapi::get_spec};
@@ -21,9 +25,17 @@ mod utils;
 mod config;
 mod mailer;
 mod github;
+mod recurrence;
+mod board;
+mod storage;
 #[allow(warnings, unused)]
 mod prisma;
 
+/// Room for multipart boundaries and headers on top of the file itself.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+/// Board events kept for viewers that fall behind before they start missing moves.
+const BOARD_EVENTS_CAPACITY: usize = 256;
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Config & logger initialization
@@ -38,6 +50,15 @@ async fn main() -> std::io::Result<()> {
         client._migrate_deploy().await.unwrap();
     }
 
+    // Issue providers initialization
+    let issue_providers = IssueProviders::new(
+        config.github_app_id,
+        &config.github_app_private_key
+    ).unwrap();
+
+    // Upload storage initialization
+    let storage = storage::create_storage(&config.storage, &config.storage_signing_secret).unwrap();
+
     // App data initialization
     let mut app_data = AppData {
         mailer: Mailer::new(
@@ -45,7 +66,13 @@ async fn main() -> std::io::Result<()> {
             &config.unisender_sender_name,
             &config.unisender_sender_email,
             "ru"
-        )
+        ),
+        sync_trigger: Arc::new(Notify::new()),
+        issue_providers,
+        attachments: config.attachments.clone(),
+        repositories: config.repositories.clone(),
+        storage,
+        board_events: broadcast::channel(BOARD_EVENTS_CAPACITY).0,
     };
 
     // Mailer initialization
@@ -54,11 +81,13 @@ async fn main() -> std::io::Result<()> {
     // GitHub worker initialization
     let shutdown_token = CancellationToken::new();
     let gh_worker = GitHubWorker::new(
-        config.github_app_id,
-        &config.github_app_private_key,
+        app_data.issue_providers.clone(),
+        app_data.repositories.token_key.clone(),
+        app_data.mailer.clone(),
         shutdown_token.clone(),
+        app_data.sync_trigger.clone(),
         Duration::from_secs(60)
-    ).await.unwrap();
+    );
 
     actix_web::rt::spawn(async move {
         if let Err(e) = gh_worker.work().await {
@@ -66,6 +95,32 @@ async fn main() -> std::io::Result<()> {
         }
     });
 
+    // Recurring task worker initialization
+    let recurrence_worker = RecurrenceWorker::new(
+        app_data.mailer.clone(),
+        shutdown_token.clone(),
+        Duration::from_secs(60)
+    );
+
+    actix_web::rt::spawn(async move {
+        if let Err(e) = recurrence_worker.work().await {
+            eprintln!("Recurring task worker error: {}", e);
+        }
+    });
+
+    // Board event listener initialization
+    let board_event_listener = BoardEventListener::new(
+        &config.database_url,
+        app_data.board_events.clone(),
+        shutdown_token.clone()
+    );
+
+    actix_web::rt::spawn(async move {
+        if let Err(e) = board_event_listener.work().await {
+            eprintln!("Board event listener error: {}", e);
+        }
+    });
+
     // Http server start
     let server = HttpServer::new(move || {
         let cors = Cors::permissive();
@@ -74,6 +129,8 @@ async fn main() -> std::io::Result<()> {
             .wrap(cors)
             .wrap(Logger::default())
             .app_data(actix_web::web::Data::new(app_data.clone()))
+            // Uploads larger than an attachment are rejected before they are written to disk
+            .app_data(MultipartFormConfig::default().total_limit(app_data.attachments.max_file_size as usize + MULTIPART_OVERHEAD))
             .document(get_spec())
             .service(scope("/api").configure(controllers::init_routes))
             .build_with(
@@ -81,6 +138,8 @@ async fn main() -> std::io::Result<()> {
                 BuildConfig::default().with(ScalarConfig::new(&"/docs")),
             )
             .service(actix_web::web::scope("/uploads").configure(controllers::init_uploads))
+            .service(actix_web::web::scope("/events").configure(controllers::init_events))
+            .service(actix_web::web::scope("/reports").configure(controllers::init_reports))
     })
     .bind("0.0.0.0:1488")?
     .run();
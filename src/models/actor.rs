@@ -0,0 +1,66 @@
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+use super::{project::RepositoryProvider, user::SelectUser};
+
+/// Built-in identities used for changes that no user made directly.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
+pub enum SystemActor {
+    #[serde(rename = "github_sync")]
+    #[strum(serialize = "github_sync")]
+    GitHubSync,
+    #[serde(rename = "gitlab_sync")]
+    #[strum(serialize = "gitlab_sync")]
+    GitLabSync,
+    #[serde(rename = "gitea_sync")]
+    #[strum(serialize = "gitea_sync")]
+    GiteaSync,
+    #[serde(rename = "recurrence")]
+    #[strum(serialize = "recurrence")]
+    Recurrence,
+}
+
+impl SystemActor {
+    /// The repository sync of a project's issue tracker.
+    pub fn repository_sync(provider: RepositoryProvider) -> Self {
+        match provider {
+            RepositoryProvider::GitHub => SystemActor::GitHubSync,
+            RepositoryProvider::GitLab => SystemActor::GitLabSync,
+            RepositoryProvider::Gitea => SystemActor::GiteaSync,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SystemActor::GitHubSync => "GitHub sync",
+            SystemActor::GitLabSync => "GitLab sync",
+            SystemActor::GiteaSync => "Gitea sync",
+            SystemActor::Recurrence => "Recurring tasks",
+        }
+    }
+}
+
+/// Who performs a change: a user or one of the system integrations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Actor {
+    User(u64),
+    System(SystemActor),
+}
+
+impl Actor {
+    pub fn user_id(&self) -> Option<u64> {
+        match self {
+            Actor::User(user_id) => Some(*user_id),
+            Actor::System(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectActor {
+    pub(crate) user: Option<SelectUser>,
+    pub(crate) system: Option<SystemActor>,
+    pub(crate) display_name: String,
+}
@@ -0,0 +1,25 @@
+use actix_multipart::form::{tempfile::TempFile, MultipartForm};
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::SelectUser;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectAttachment {
+    pub(crate) id: u64,
+    pub(crate) task_id: u64,
+    pub(crate) name: String,
+    pub(crate) size: u64,
+    pub(crate) mime_type: String,
+    /// SHA-256 of the content, hex encoded
+    pub(crate) checksum: String,
+    pub(crate) uploader: Option<SelectUser>,
+    pub(crate) created_at: u64,
+    pub(crate) download_url: String,
+}
+
+#[derive(MultipartForm)]
+pub struct UploadAttachment {
+    pub(crate) file: TempFile
+}
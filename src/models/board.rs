@@ -0,0 +1,43 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{task::SelectTask, workflow::SelectProjectStatus};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectBoardColumn {
+    pub(crate) status: SelectProjectStatus,
+    pub(crate) tasks: Vec<SelectTask>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectBoard {
+    pub(crate) project_id: u64,
+    /// One column per status of the project, in workflow order
+    pub(crate) columns: Vec<SelectBoardColumn>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct MoveTaskRequest {
+    /// Key of the status whose column the task moves to
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub status: String,
+    /// Task of the target column to place the moved task right after. The task goes to the top when empty
+    #[garde(skip)]
+    pub after_id: Option<u64>,
+    /// Confirms completing the open subtasks when the task moves to a Done column
+    #[garde(skip)]
+    pub complete_subtasks: Option<bool>,
+}
+
+/// Sent to everyone watching a project board when a task moves.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BoardEvent {
+    pub project_id: u64,
+    pub task_id: u64,
+    pub status: String,
+    pub board_position: f64,
+    pub moved_by: u64,
+}
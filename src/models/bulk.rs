@@ -0,0 +1,53 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::task::SelectTask;
+
+/// Changes applied to every listed task. Tasks the user can't change are reported
+/// in the results and left out, the others are changed in one transaction.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct BulkTaskRequest {
+    #[garde(length(min = 1, max = 100))]
+    #[schemars(length(min = 1, max = 100))]
+    pub task_ids: Vec<u64>,
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub status: Option<String>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub add_assignee_ids: Vec<u64>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub remove_assignee_ids: Vec<u64>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub add_label_ids: Vec<u64>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub remove_label_ids: Vec<u64>,
+    #[garde(skip)]
+    pub due_date: Option<u64>,
+    #[garde(skip)]
+    pub clear_due_date: Option<bool>,
+    /// Deletes the tasks, moving their subtasks up. Can't be combined with other changes
+    #[garde(skip)]
+    pub delete: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectBulkTaskResult {
+    pub(crate) task_id: u64,
+    pub(crate) success: bool,
+    pub(crate) error: Option<String>,
+    /// The changed task, absent for deleted and failed ones
+    pub(crate) task: Option<SelectTask>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectBulkTaskResponse {
+    pub(crate) results: Vec<SelectBulkTaskResult>,
+    pub(crate) succeeded: u64,
+    pub(crate) failed: u64,
+}
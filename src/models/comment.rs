@@ -0,0 +1,50 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::SelectUser;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectComment {
+    pub(crate) id: u64,
+    pub(crate) task_id: u64,
+    pub(crate) author: SelectUser,
+    /// Empty for deleted comments
+    pub(crate) body: String,
+    pub(crate) created_at: u64,
+    pub(crate) edited_at: Option<u64>,
+    pub(crate) deleted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectCommentPage {
+    pub(crate) items: Vec<SelectComment>,
+    pub(crate) page: u64,
+    pub(crate) per_page: u64,
+    pub(crate) total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct SelectCommentsRequest {
+    #[garde(range(min = 1))]
+    #[schemars(range(min = 1))]
+    pub page: Option<u64>,
+    #[garde(range(min = 1, max = 100))]
+    #[schemars(range(min = 1, max = 100))]
+    pub per_page: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateCommentRequest {
+    #[garde(length(min = 1, max = 4096))]
+    #[schemars(length(min = 1, max = 4096))]
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct UpdateCommentRequest {
+    #[garde(length(min = 1, max = 4096))]
+    #[schemars(length(min = 1, max = 4096))]
+    pub body: String,
+}
@@ -0,0 +1,26 @@
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::workflow::StatusCategory;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectLinkedTask {
+    pub(crate) id: u64,
+    pub(crate) name: String,
+    pub(crate) status: String,
+    pub(crate) status_category: StatusCategory,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskDependencies {
+    /// Tasks that can't proceed until this one is finished
+    pub(crate) blocks: Vec<SelectLinkedTask>,
+    /// Tasks that have to be finished before this one can proceed
+    pub(crate) blocked_by: Vec<SelectLinkedTask>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct CreateDependencyRequest {
+    pub(crate) blocker_id: u64,
+}
@@ -0,0 +1,32 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectExternalComment {
+    pub(crate) id: u64,
+    pub(crate) external_id: String,
+    pub(crate) author: String,
+    pub(crate) author_url: Option<String>,
+    pub(crate) body: String,
+    pub(crate) url: Option<String>,
+    pub(crate) created_at: u64,
+    pub(crate) updated_at: u64,
+    pub(crate) edited: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskDiscussion {
+    pub(crate) task_id: u64,
+    pub(crate) issue: Option<u64>,
+    pub(crate) synced_at: Option<u64>,
+    pub(crate) comments: Vec<SelectExternalComment>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateDiscussionReplyRequest {
+    #[garde(length(min = 1, max = 65536))]
+    #[schemars(length(min = 1, max = 65536))]
+    pub body: String,
+}
@@ -0,0 +1,46 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::actor::SelectActor;
+use super::task::SelectTask;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskChange {
+    pub(crate) id: u64,
+    pub(crate) task_id: u64,
+    /// One of `name`, `description`, `status`, `due_date`, `assigned_issue`,
+    /// `priority`, `estimate`, `labels` or `assignees`
+    pub(crate) field: String,
+    pub(crate) old_value: Option<Value>,
+    pub(crate) new_value: Option<Value>,
+    pub(crate) changed_by: Option<SelectActor>,
+    pub(crate) changed_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskHistoryPage {
+    pub(crate) items: Vec<SelectTaskChange>,
+    pub(crate) page: u64,
+    pub(crate) per_page: u64,
+    pub(crate) total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct SelectTaskHistoryRequest {
+    #[garde(range(min = 1))]
+    #[schemars(range(min = 1))]
+    pub page: Option<u64>,
+    #[garde(range(min = 1, max = 100))]
+    #[schemars(range(min = 1, max = 100))]
+    pub per_page: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskRevert {
+    pub(crate) task: SelectTask,
+    /// Former assignees left out because they are no longer project participants
+    pub(crate) skipped_assignee_ids: Vec<u64>,
+}
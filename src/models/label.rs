@@ -0,0 +1,33 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectLabel {
+    pub(crate) id: u64,
+    pub(crate) project_id: u64,
+    pub(crate) name: String,
+    /// Hex color like `#1f883d`
+    pub(crate) color: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateLabelRequest {
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub name: String,
+    #[garde(pattern(r"^#[0-9a-fA-F]{6}$"))]
+    #[schemars(regex(pattern = r"^#[0-9a-fA-F]{6}$"))]
+    pub color: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct UpdateLabelRequest {
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub name: Option<String>,
+    #[garde(pattern(r"^#[0-9a-fA-F]{6}$"))]
+    #[schemars(regex(pattern = r"^#[0-9a-fA-F]{6}$"))]
+    pub color: Option<String>,
+}
@@ -3,3 +3,20 @@ pub mod project;
 pub mod task;
 pub mod auth;
 pub mod notification;
+pub mod sync;
+pub mod actor;
+pub mod discussion;
+pub mod comment;
+pub mod attachment;
+pub mod storage;
+pub mod dependency;
+pub mod workflow;
+pub mod board;
+pub mod label;
+pub mod time_entry;
+pub mod history;
+pub mod search;
+pub mod bulk;
+pub mod recurrence;
+pub mod template;
+pub mod transfer;
@@ -2,9 +2,28 @@ use apistos::ApiComponent;
 use garde::Validate;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
 
 use super::{task::SelectTask, user::SelectUser};
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RepositoryProvider {
+    GitHub,
+    GitLab,
+    Gitea
+}
+
+/// Everything needed to reach the issue tracker of a project's repository.
+#[derive(Debug, Clone)]
+pub struct RepositoryLink {
+    pub provider: RepositoryProvider,
+    pub repository_id: String,
+    pub base_url: Option<String>,
+    pub token: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
 pub struct SelectProject {
     pub(crate) id: u64,
@@ -15,6 +34,10 @@ pub struct SelectProject {
     pub(crate) members: Vec<SelectUser>,
     pub(crate) tasks: Vec<SelectTask>,
     pub(crate) repository_id: Option<String>,
+    pub(crate) repository_provider: RepositoryProvider,
+    pub(crate) repository_base_url: Option<String>,
+    /// Grows with every change, sent as the `ETag` of the project
+    pub(crate) version: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
@@ -41,4 +64,13 @@ pub struct UpdateProjectRequest {
     pub owner_id: Option<u64>,
     #[garde(skip)]
     pub repository_id: Option<String>,
+    #[garde(skip)]
+    pub repository_provider: Option<RepositoryProvider>,
+    /// Must use https. Hosts resolving to private addresses need to be allowed by the server
+    #[garde(pattern(r"^https://"))]
+    #[schemars(regex(pattern = r"^https://"))]
+    pub repository_base_url: Option<String>,
+    #[garde(length(min = 1, max = 1024))]
+    #[schemars(length(min = 1, max = 1024))]
+    pub repository_token: Option<String>,
 }
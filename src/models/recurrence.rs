@@ -0,0 +1,140 @@
+use std::fmt;
+use std::str::FromStr;
+
+use apistos::ApiComponent;
+use chrono::Weekday;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+#[derive(Debug, Clone, Copy, PartialEq, strum_macros::Display, EnumString)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly
+}
+
+/// The supported subset of an iCalendar RRULE: `FREQ` of `DAILY`, `WEEKLY` or `MONTHLY`,
+/// `INTERVAL`, `BYDAY` for weekly and `BYMONTHDAY` for monthly rules,
+/// e.g. `FREQ=WEEKLY;BYDAY=MO,TH` or `FREQ=MONTHLY;BYMONTHDAY=5`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub interval: u32,
+    pub weekdays: Vec<Weekday>,
+    pub month_day: Option<u32>,
+}
+
+const WEEKDAYS: [(&'static str, Weekday); 7] = [
+    ("MO", Weekday::Mon),
+    ("TU", Weekday::Tue),
+    ("WE", Weekday::Wed),
+    ("TH", Weekday::Thu),
+    ("FR", Weekday::Fri),
+    ("SA", Weekday::Sat),
+    ("SU", Weekday::Sun),
+];
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut weekdays = vec![];
+        let mut month_day = None;
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(|| format!("Invalid rule part {}", part))?;
+            match key.to_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(
+                        RecurrenceFrequency::from_str(&value.to_uppercase())
+                            .map_err(|_| format!("Unsupported frequency {}", value))?,
+                    );
+                }
+                "INTERVAL" => {
+                    interval = value.parse::<u32>().ok()
+                        .filter(|interval| (1..=99).contains(interval))
+                        .ok_or_else(|| format!("Invalid interval {}", value))?;
+                }
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        let weekday = WEEKDAYS
+                            .iter()
+                            .find(|(name, _)| name.eq_ignore_ascii_case(day))
+                            .map(|(_, weekday)| *weekday)
+                            .ok_or_else(|| format!("Invalid weekday {}", day))?;
+                        if !weekdays.contains(&weekday) {
+                            weekdays.push(weekday);
+                        }
+                    }
+                }
+                "BYMONTHDAY" => {
+                    month_day = Some(
+                        value.parse::<u32>().ok()
+                            .filter(|day| (1..=31).contains(day))
+                            .ok_or_else(|| format!("Invalid day of month {}", value))?,
+                    );
+                }
+                _ => return Err(format!("Unsupported rule part {}", key)),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| "The rule has no FREQ".to_string())?;
+        if !weekdays.is_empty() && frequency != RecurrenceFrequency::Weekly {
+            return Err("BYDAY is only supported in weekly rules".to_string());
+        }
+        if month_day.is_some() && frequency != RecurrenceFrequency::Monthly {
+            return Err("BYMONTHDAY is only supported in monthly rules".to_string());
+        }
+        weekdays.sort_by_key(|weekday| weekday.num_days_from_monday());
+        Ok(RecurrenceRule { frequency, interval, weekdays, month_day })
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.weekdays.is_empty() {
+            let days: Vec<&str> = self.weekdays
+                .iter()
+                .filter_map(|weekday| WEEKDAYS.iter().find(|(_, day)| day == weekday).map(|(name, _)| *name))
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if let Some(month_day) = self.month_day {
+            write!(f, ";BYMONTHDAY={}", month_day)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectRecurrence {
+    pub(crate) id: u64,
+    /// Normalized rule, e.g. `FREQ=WEEKLY;BYDAY=MO`
+    pub(crate) rule: String,
+    pub(crate) until: Option<u64>,
+    /// Stopped series create no more instances
+    pub(crate) active: bool,
+    /// Due date the next instance will get, absent once the series is over
+    pub(crate) next_occurrence: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct SetRecurrenceRequest {
+    /// RRULE subset: `FREQ=DAILY|WEEKLY|MONTHLY`, `INTERVAL`, `BYDAY` for weekly and `BYMONTHDAY` for monthly rules
+    #[garde(length(min = 1, max = 255))]
+    #[schemars(length(min = 1, max = 255))]
+    pub rule: String,
+    /// No instances are due after this time
+    #[garde(skip)]
+    pub until: Option<u64>,
+}
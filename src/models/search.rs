@@ -0,0 +1,54 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SearchResultKind {
+    Project,
+    Task,
+    Comment
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectSearchResult {
+    pub(crate) kind: SearchResultKind,
+    /// Id of the project, task or comment, depending on `kind`
+    pub(crate) id: u64,
+    pub(crate) project_id: u64,
+    /// Task the result belongs to, absent for projects
+    pub(crate) task_id: Option<u64>,
+    /// Name of the project or task
+    pub(crate) title: String,
+    /// Matching fragments with the matched words wrapped in `<mark>` tags
+    pub(crate) snippet: String,
+    pub(crate) rank: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectSearchResults {
+    pub(crate) items: Vec<SelectSearchResult>,
+    pub(crate) page: u64,
+    pub(crate) per_page: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct SearchQuery {
+    /// Words to search for. Supports quoted phrases, `or` and `-` to exclude a word
+    #[garde(length(min = 1, max = 255))]
+    #[schemars(length(min = 1, max = 255))]
+    pub q: String,
+    #[garde(skip)]
+    pub kind: Option<SearchResultKind>,
+    #[garde(skip)]
+    pub project_id: Option<u64>,
+    #[garde(range(min = 1))]
+    #[schemars(range(min = 1))]
+    pub page: Option<u64>,
+    #[garde(range(min = 1, max = 100))]
+    #[schemars(range(min = 1, max = 100))]
+    pub per_page: Option<u64>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct PresignedUrlQuery {
+    pub(crate) expires: i64,
+    pub(crate) signature: String,
+    pub(crate) content_type: Option<String>,
+    pub(crate) content_disposition: Option<String>,
+}
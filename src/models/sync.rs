@@ -0,0 +1,30 @@
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectSyncRun {
+    pub(crate) id: u64,
+    pub(crate) started_at: u64,
+    pub(crate) finished_at: u64,
+    pub(crate) success: bool,
+    pub(crate) error: Option<String>,
+    pub(crate) items_created: u64,
+    pub(crate) items_updated: u64,
+    pub(crate) duration_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectProjectSync {
+    pub(crate) project_id: u64,
+    pub(crate) last_run_at: Option<u64>,
+    pub(crate) last_success_at: Option<u64>,
+    pub(crate) last_error: Option<String>,
+    pub(crate) last_error_at: Option<u64>,
+    pub(crate) consecutive_failures: u64,
+    pub(crate) items_created: u64,
+    pub(crate) items_updated: u64,
+    pub(crate) duration_ms: u64,
+    pub(crate) resync_requested: bool,
+    pub(crate) history: Vec<SelectSyncRun>,
+}
@@ -4,8 +4,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use crate::models::project::SelectProject;
-use super::user::SelectUser;
+use super::{actor::SelectActor, label::SelectLabel, user::SelectUser, workflow::StatusCategory};
 
+/// Statuses every new project starts with. Projects can rename, reorder or replace them,
+/// so task statuses are stored and returned as the keys of the project's statuses.
 #[derive(Debug, Serialize, Deserialize, Clone, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
 #[strum(serialize_all = "snake_case")]
 pub enum TaskStatus {
@@ -17,6 +19,48 @@ pub enum TaskStatus {
     Cancelled
 }
 
+impl TaskStatus {
+    pub const DEFAULT_WORKFLOW: [TaskStatus; 6] = [
+        TaskStatus::Todo,
+        TaskStatus::InProgress,
+        TaskStatus::InReview,
+        TaskStatus::Blocked,
+        TaskStatus::Done,
+        TaskStatus::Cancelled,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "Todo",
+            TaskStatus::InProgress => "In progress",
+            TaskStatus::InReview => "In review",
+            TaskStatus::Done => "Done",
+            TaskStatus::Blocked => "Blocked",
+            TaskStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn category(&self) -> StatusCategory {
+        match self {
+            TaskStatus::Todo => StatusCategory::Todo,
+            TaskStatus::InProgress | TaskStatus::InReview | TaskStatus::Blocked => StatusCategory::InProgress,
+            TaskStatus::Done => StatusCategory::Done,
+            TaskStatus::Cancelled => StatusCategory::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaskPriority {
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
 pub struct SelectTask {
     pub id: u64,
@@ -24,15 +68,123 @@ pub struct SelectTask {
     pub name: String,
     pub description: String,
     pub attached_to: Vec<SelectUser>,
-    pub status: TaskStatus,
+    /// Key of one of the project's statuses
+    pub status: String,
+    pub status_category: StatusCategory,
     pub due_date: Option<u64>,
     pub assigned_issue: Option<u64>,
-    pub project: SelectProject
+    pub project: SelectProject,
+    pub created_by: Option<SelectActor>,
+    pub updated_by: Option<SelectActor>,
+    pub parent_id: Option<u64>,
+    pub position: u64,
+    /// Order of the task within its status column on the board
+    pub board_position: f64,
+    pub subtasks: SubtaskProgress,
+    pub priority: TaskPriority,
+    /// Story points or hours, whichever the project counts in
+    pub estimate: Option<f64>,
+    pub labels: Vec<SelectLabel>,
+    /// Seconds logged on the task, running timers included
+    pub time_spent: u64,
+    /// Recurring series the task is an instance of
+    pub recurrence_id: Option<u64>,
+    /// Grows with every change, sent as the `ETag` of the task
+    pub version: u64,
 }
 
+/// Completion of the direct subtasks of a task. Cancelled subtasks are not counted.
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SubtaskProgress {
+    pub done: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, strum_macros::Display, JsonSchema, ApiComponent, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaskSortField {
+    #[default]
+    DueDate,
+    CreatedAt,
+    Name,
+    Estimate,
+    BoardPosition,
+    Id
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, JsonSchema, ApiComponent, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectMyTasksRequest {
+    pub project_id: Option<u64>,
+}
+
+/// Without `project_id` only tasks assigned to the current user are listed,
+/// with it all tasks of the project.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
 pub struct SelectTaskRequest {
+    #[garde(skip)]
     pub project_id: Option<u64>,
+    /// Comma separated status keys
+    #[garde(length(min = 1, max = 1024))]
+    #[schemars(length(min = 1, max = 1024))]
+    pub status: Option<String>,
+    #[garde(skip)]
+    pub assignee_id: Option<u64>,
+    /// Only tasks nobody is assigned to
+    #[garde(skip)]
+    pub unassigned: Option<bool>,
+    #[garde(skip)]
+    pub due_from: Option<u64>,
+    #[garde(skip)]
+    pub due_to: Option<u64>,
+    /// Only unfinished tasks past their due date
+    #[garde(skip)]
+    pub overdue: Option<bool>,
+    #[garde(skip)]
+    pub assigned_issue: Option<u64>,
+    /// Only tasks with (or without) a linked issue
+    #[garde(skip)]
+    pub has_issue: Option<bool>,
+    /// Searched in the name and description, case insensitive
+    #[garde(length(min = 1, max = 255))]
+    #[schemars(length(min = 1, max = 255))]
+    pub q: Option<String>,
+    #[garde(skip)]
+    pub priority: Option<TaskPriority>,
+    /// Only tasks having this label
+    #[garde(skip)]
+    pub label_id: Option<u64>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub min_estimate: Option<f64>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub max_estimate: Option<f64>,
+    #[garde(skip)]
+    pub sort: Option<TaskSortField>,
+    #[garde(skip)]
+    pub direction: Option<SortDirection>,
+    /// Id of the last task of the previous page
+    #[garde(skip)]
+    pub cursor: Option<u64>,
+    #[garde(range(min = 1, max = 100))]
+    #[schemars(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskPage {
+    pub items: Vec<SelectTask>,
+    /// Cursor of the next page, absent on the last one
+    pub next_cursor: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
@@ -50,7 +202,52 @@ pub struct CreateTaskRequest {
     #[garde(skip)]
     pub due_date: Option<u64>,
     #[garde(skip)]
-    pub assigned_issue: Option<u64>
+    pub assigned_issue: Option<u64>,
+    #[garde(skip)]
+    pub parent_id: Option<u64>,
+    #[garde(skip)]
+    pub priority: Option<TaskPriority>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub estimate: Option<f64>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub label_ids: Vec<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateSubtaskRequest {
+    #[garde(length(min = 3, max = 255))]
+    #[schemars(length(min = 3, max = 255))]
+    pub name: String,
+    #[garde(length(min = 0, max = 1024))]
+    #[schemars(length(min = 0, max = 1024))]
+    pub description: String,
+    #[garde(skip)]
+    pub attached_to: Vec<u64>,
+    #[garde(skip)]
+    pub due_date: Option<u64>,
+    #[garde(skip)]
+    pub priority: Option<TaskPriority>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub estimate: Option<f64>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub label_ids: Vec<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct ReorderSubtasksRequest {
+    /// Every subtask of the task, in the new order
+    #[garde(length(min = 1))]
+    pub task_ids: Vec<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct DeleteTaskQuery {
+    /// Delete the subtasks too instead of moving them to the parent of the deleted task
+    pub cascade: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
@@ -61,10 +258,23 @@ pub struct UpdateTaskRequest {
     #[garde(length(min = 0, max = 1024))]
     #[schemars(length(min = 0, max = 1024))]
     pub description: Option<String>,
-    #[garde(skip)]
-    pub status: Option<TaskStatus>,
+    /// Key of one of the project's statuses
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub status: Option<String>,
     #[garde(skip)]
     pub due_date: Option<u64>,
     #[garde(skip)]
-    pub assigned_issue: Option<u64>
+    pub assigned_issue: Option<u64>,
+    /// Confirms completing the open subtasks along with the task
+    #[garde(skip)]
+    pub complete_subtasks: Option<bool>,
+    #[garde(skip)]
+    pub priority: Option<TaskPriority>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub estimate: Option<f64>,
+    /// Replaces the labels of the task
+    #[garde(skip)]
+    pub label_ids: Option<Vec<u64>>,
 }
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{task::TaskPriority, user::SelectUser};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTemplateRole {
+    pub(crate) name: String,
+    /// Filled in when a project is created without a user for the role
+    pub(crate) default_user: Option<SelectUser>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTaskTemplate {
+    pub(crate) id: u64,
+    pub(crate) project_template_id: Option<u64>,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) priority: TaskPriority,
+    pub(crate) estimate: Option<f64>,
+    /// Roles whose users are assigned to the created task
+    pub(crate) assignee_roles: Vec<String>,
+    /// Days from the start date to the due date of the created task
+    pub(crate) due_offset_days: Option<u64>,
+    pub(crate) position: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectProjectTemplate {
+    pub(crate) id: u64,
+    pub(crate) created_at: u64,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) roles: Vec<SelectTemplateRole>,
+    pub(crate) tasks: Vec<SelectTaskTemplate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct TaskTemplateRequest {
+    #[garde(length(min = 3, max = 255))]
+    #[schemars(length(min = 3, max = 255))]
+    pub name: String,
+    #[garde(length(min = 0, max = 1024))]
+    #[schemars(length(min = 0, max = 1024))]
+    pub description: String,
+    #[garde(skip)]
+    pub priority: Option<TaskPriority>,
+    #[garde(range(min = 0.0))]
+    #[schemars(range(min = 0.0))]
+    pub estimate: Option<f64>,
+    #[garde(length(max = 16), inner(length(min = 1, max = 64)))]
+    #[schemars(length(max = 16))]
+    #[serde(default)]
+    pub assignee_roles: Vec<String>,
+    #[garde(range(max = 3650))]
+    #[schemars(range(max = 3650))]
+    pub due_offset_days: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct TemplateRoleRequest {
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub name: String,
+    #[garde(skip)]
+    pub default_user_id: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateProjectTemplateRequest {
+    #[garde(length(min = 1, max = 256))]
+    #[schemars(length(min = 1, max = 256))]
+    pub name: String,
+    #[garde(length(min = 1, max = 1024))]
+    #[schemars(length(min = 1, max = 1024))]
+    pub description: String,
+    #[garde(length(max = 32), dive)]
+    #[schemars(length(max = 32))]
+    #[serde(default)]
+    pub roles: Vec<TemplateRoleRequest>,
+    #[garde(length(max = 200), dive)]
+    #[schemars(length(max = 200))]
+    #[serde(default)]
+    pub tasks: Vec<TaskTemplateRequest>,
+}
+
+/// Saves an existing project as a template.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct SaveProjectTemplateRequest {
+    #[garde(length(min = 1, max = 256))]
+    #[schemars(length(min = 1, max = 256))]
+    pub name: String,
+    #[garde(length(min = 1, max = 1024))]
+    #[schemars(length(min = 1, max = 1024))]
+    pub description: String,
+    /// Turns every participant into a role named after their username, with them as the default user
+    #[garde(skip)]
+    pub include_members: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateTaskFromTemplateRequest {
+    #[garde(skip)]
+    pub project_id: u64,
+    /// User for each role of the template
+    #[garde(skip)]
+    #[serde(default)]
+    pub assignees: HashMap<String, u64>,
+    /// Due offsets count from this time, now by default
+    #[garde(skip)]
+    pub start_date: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateProjectFromTemplateRequest {
+    #[garde(length(min = 1, max = 256))]
+    #[schemars(length(min = 1, max = 256))]
+    pub name: String,
+    #[garde(length(min = 1, max = 1024))]
+    #[schemars(length(min = 1, max = 1024))]
+    pub description: String,
+    /// User for each role of the template, overriding its default user
+    #[garde(skip)]
+    #[serde(default)]
+    pub assignees: HashMap<String, u64>,
+    /// Due offsets count from this time, now by default
+    #[garde(skip)]
+    pub start_date: Option<u64>,
+}
@@ -0,0 +1,59 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::SelectUser;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTimeEntry {
+    pub(crate) id: u64,
+    pub(crate) task_id: u64,
+    pub(crate) user: SelectUser,
+    pub(crate) started_at: u64,
+    /// Empty while the timer is running
+    pub(crate) ended_at: Option<u64>,
+    /// Seconds, counted up to now for a running timer
+    pub(crate) duration: u64,
+    pub(crate) note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct CreateWorklogRequest {
+    #[garde(skip)]
+    pub started_at: u64,
+    #[garde(range(min = 1, max = 1440))]
+    #[schemars(range(min = 1, max = 1440))]
+    pub duration_minutes: u64,
+    #[garde(length(max = 1024))]
+    #[schemars(length(max = 1024))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct TimeReportQuery {
+    /// Start of the period, inclusive. Defaults to the first entry
+    pub from: Option<u64>,
+    /// End of the period, exclusive. Defaults to now
+    pub to: Option<u64>,
+    pub user_id: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTimeReportRow {
+    pub(crate) user: SelectUser,
+    pub(crate) task_id: u64,
+    pub(crate) task_name: String,
+    pub(crate) seconds: u64,
+}
+
+/// Time spent on a project within a period, per user and task.
+/// Entries crossing the period bounds are counted for their part inside it.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectTimeReport {
+    pub(crate) project_id: u64,
+    pub(crate) from: Option<u64>,
+    pub(crate) to: u64,
+    pub(crate) rows: Vec<SelectTimeReportRow>,
+    pub(crate) total_seconds: u64,
+}
@@ -0,0 +1,45 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{task::SelectTask, user::SelectUser};
+
+/// Moves a task with its subtasks to another project.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct MoveTaskToProjectRequest {
+    #[garde(skip)]
+    pub project_id: u64,
+    /// Unassigns users who don't participate in the target project instead of rejecting the move
+    #[garde(skip)]
+    pub remove_non_members: Option<bool>,
+    /// Keeps the linked issue, which is cleared by default. Both projects must use the same repository
+    #[garde(skip)]
+    pub keep_issue: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectMovedTask {
+    pub(crate) task: SelectTask,
+    /// The task and its subtasks
+    pub(crate) moved_task_ids: Vec<u64>,
+    /// Users unassigned because they don't participate in the target project
+    pub(crate) removed_assignees: Vec<SelectUser>,
+}
+
+/// Creates a copy of a task next to it, in the initial status of the project.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct DuplicateTaskRequest {
+    #[garde(length(min = 3, max = 255))]
+    #[schemars(length(min = 3, max = 255))]
+    pub name: Option<String>,
+    /// False by default
+    #[garde(skip)]
+    pub include_assignees: Option<bool>,
+    /// True by default
+    #[garde(skip)]
+    pub include_description: Option<bool>,
+    /// Copies the subtasks with the same options, false by default
+    #[garde(skip)]
+    pub include_subtasks: Option<bool>,
+}
@@ -20,6 +20,14 @@ pub struct SelectUserQuery {
     pub(crate) username: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct AvatarQuery {
+    /// Side in pixels, rounded up to a stored size
+    pub(crate) size: Option<u32>,
+    /// Format of the generated avatar shown when there is no uploaded one: `svg` or `png`
+    pub(crate) format: Option<String>,
+}
+
 #[derive(MultipartForm)]
 pub struct ChangeAvatar {
     #[multipart(limit = "2mb")]
@@ -0,0 +1,75 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// What a status means regardless of how a project names it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, strum_macros::Display, JsonSchema, ApiComponent, EnumString, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StatusCategory {
+    Todo,
+    InProgress,
+    Done,
+    Cancelled
+}
+
+impl StatusCategory {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, StatusCategory::Done | StatusCategory::Cancelled)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectProjectStatus {
+    pub(crate) id: u64,
+    pub(crate) key: String,
+    pub(crate) name: String,
+    pub(crate) category: StatusCategory,
+    pub(crate) position: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectStatusTransition {
+    pub(crate) from: String,
+    pub(crate) to: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct SelectWorkflow {
+    pub(crate) project_id: u64,
+    pub(crate) statuses: Vec<SelectProjectStatus>,
+    /// Allowed moves between statuses. When empty, a task can move between any statuses
+    pub(crate) transitions: Vec<SelectStatusTransition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct WorkflowStatusRequest {
+    #[garde(length(min = 1, max = 64), pattern(r"^[a-z0-9_]+$"))]
+    #[schemars(length(min = 1, max = 64), regex(pattern = r"^[a-z0-9_]+$"))]
+    pub key: String,
+    #[garde(length(min = 1, max = 64))]
+    #[schemars(length(min = 1, max = 64))]
+    pub name: String,
+    #[garde(skip)]
+    pub category: StatusCategory,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct StatusTransitionRequest {
+    #[garde(skip)]
+    pub from: String,
+    #[garde(skip)]
+    pub to: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+pub struct UpdateWorkflowRequest {
+    /// Every status of the project, in board order. Statuses are matched by key
+    #[garde(length(min = 1, max = 32), dive)]
+    #[schemars(length(min = 1, max = 32))]
+    pub statuses: Vec<WorkflowStatusRequest>,
+    #[garde(dive)]
+    pub transitions: Vec<StatusTransitionRequest>,
+}
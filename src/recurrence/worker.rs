@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+use tokio::time::{interval, Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    mailer::mailer::Mailer,
+    services::{
+        lease::{hold_lease, release_lease},
+        recurrence::create_due_instances,
+    },
+};
+
+const LOG_TAG: &'static str = "RecurrenceWorker";
+/// Name of the lease that makes sure only one replica creates instances.
+const LEASE_NAME: &'static str = "recurrence_worker";
+
+pub struct RecurrenceWorker {
+    mailer: Mailer,
+    cancel_token: CancellationToken,
+    process_interval: Duration,
+    is_leader: AtomicBool,
+}
+
+impl RecurrenceWorker {
+    pub fn new(mailer: Mailer, cancel_token: CancellationToken, process_interval: Duration) -> Self {
+        Self {
+            mailer,
+            cancel_token,
+            process_interval,
+            is_leader: AtomicBool::new(false),
+        }
+    }
+
+    pub async fn work(&self) -> Result<()> {
+        log::info!(target: LOG_TAG, "Recurring task worker started");
+        let mut ticker = interval(self.process_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    if hold_lease(LEASE_NAME, self.process_interval, &self.is_leader).await {
+                        self.process_series().await;
+                    }
+                }
+                _ = self.cancel_token.cancelled() => {
+                    log::info!(target: LOG_TAG, "Graceful shutdown triggered");
+                    if self.is_leader.load(Ordering::Relaxed) {
+                        release_lease(LEASE_NAME).await.ok();
+                    }
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn process_series(&self) {
+        match create_due_instances(&self.mailer).await {
+            Ok(0) => {}
+            Ok(created) => log::info!(target: LOG_TAG, "Created {} recurring task instances", created),
+            Err(e) => log::error!(target: LOG_TAG, "Failed to create recurring task instances: {}", e),
+        }
+    }
+}
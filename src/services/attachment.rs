@@ -0,0 +1,260 @@
+use std::path::Path;
+
+use chrono::Utc;
+use prisma_client_rust::{Direction, QueryError};
+use sha2::{Digest, Sha256};
+
+use crate::config::AttachmentSettings;
+use crate::storage::Storage;
+use crate::models::attachment::SelectAttachment;
+use crate::prisma::{attachment, project, task, user};
+use crate::services::common::create_prisma_client;
+use crate::services::task::check_member_from_task;
+use crate::services::user::{is_project_owner, user_data_to_response};
+
+const LOG_TAG: &'static str = "AttachmentService";
+/// Enough of the file to recognize every format known to `infer`.
+const SNIFF_LENGTH: usize = 8192;
+
+pub fn attachment_to_response(attachment: &attachment::Data) -> SelectAttachment {
+    SelectAttachment {
+        id: attachment.id as u64,
+        task_id: attachment.task_id as u64,
+        name: attachment.name.clone(),
+        size: attachment.size as u64,
+        mime_type: attachment.mime_type.clone(),
+        checksum: attachment.checksum.clone(),
+        uploader: attachment.uploader.as_ref()
+            .and_then(|uploader| uploader.as_deref())
+            .map(user_data_to_response),
+        created_at: attachment.created_at.timestamp() as u64,
+        download_url: format!("/uploads/attachments/{}", attachment.id),
+    }
+}
+
+/// Detects the MIME type from the file content rather than trusting the client.
+fn sniff_mime_type(data: &[u8]) -> String {
+    let head = &data[..data.len().min(SNIFF_LENGTH)];
+    let mime_type = match infer::get(head) {
+        Some(kind) => kind.mime_type(),
+        None if std::str::from_utf8(head).is_ok() => "text/plain",
+        None => "application/octet-stream",
+    };
+    mime_type.to_string()
+}
+
+async fn get_task_project_id(task_id: u64) -> Result<u64, String> {
+    let client = create_prisma_client().await?;
+    client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .map(|task| task.project_id as u64)
+        .ok_or_else(|| "Task not found".to_string())
+}
+
+pub async fn get_task_attachments(user_id: u64, task_id: u64) -> Result<Vec<SelectAttachment>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let attachments = client
+        .attachment()
+        .find_many(vec![attachment::task_id::equals(task_id as i32)])
+        .with(attachment::uploader::fetch())
+        .order_by(attachment::created_at::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get attachments: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(attachments.iter().map(attachment_to_response).collect())
+}
+
+/// Total size in bytes of the attachments of all tasks in a project.
+pub async fn get_project_storage_usage(project_id: u64) -> Result<u64, String> {
+    let client = create_prisma_client().await?;
+    let attachments = client
+        .attachment()
+        .find_many(vec![attachment::task::is(vec![task::project_id::equals(project_id as i32)])])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project storage usage: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(attachments.iter().map(|attachment| attachment.size as u64).sum())
+}
+
+/// Error of an upload that the attachment limits or the storage rejected.
+#[derive(Debug)]
+pub enum AttachmentUploadError {
+    /// The file doesn't fit into the limits, with the reason
+    TooLarge(String),
+    Failed(String),
+}
+
+impl From<String> for AttachmentUploadError {
+    fn from(err: String) -> Self {
+        AttachmentUploadError::Failed(err)
+    }
+}
+
+fn quota_exceeded(settings: &AttachmentSettings, used: u64) -> AttachmentUploadError {
+    AttachmentUploadError::TooLarge(format!(
+        "Project attachments would exceed the quota of {} bytes ({} bytes used)",
+        settings.project_quota, used
+    ))
+}
+
+/// Copies an uploaded file into the storage and records its metadata.
+/// The project quota is checked again when recording, so concurrent uploads can't exceed it together.
+pub async fn create_attachment(
+    storage: &dyn Storage,
+    settings: &AttachmentSettings,
+    user_id: u64,
+    task_id: u64,
+    name: &str,
+    file: &Path,
+) -> Result<SelectAttachment, AttachmentUploadError> {
+    check_member_from_task(user_id, task_id).await?;
+    let project_id = get_task_project_id(task_id).await?;
+
+    let size = tokio::fs::metadata(file).await.map_err(|err| err.to_string())?.len();
+    if size > settings.max_file_size {
+        return Err(AttachmentUploadError::TooLarge(format!("File is larger than {} bytes", settings.max_file_size)));
+    }
+    let used = get_project_storage_usage(project_id).await?;
+    if used + size > settings.project_quota {
+        return Err(quota_exceeded(settings, used));
+    }
+
+    let data = tokio::fs::read(file).await.map_err(|err| err.to_string())?;
+    let mime_type = sniff_mime_type(&data);
+    let checksum = hex::encode(Sha256::digest(&data));
+
+    let storage_key = format!(
+        "attachments/{}/{}-{}",
+        project_id,
+        Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+        &checksum[..16]
+    );
+    storage.put(&storage_key, data, &mime_type).await.map_err(|err| {
+        log::error!(target: LOG_TAG, "Failed to store attachment: {:?}", err);
+        format!("{:#}", err)
+    })?;
+
+    let client = create_prisma_client().await?;
+    let project_quota = settings.project_quota;
+    let key = storage_key.clone();
+    let name = name.to_string();
+    let attachment = client
+        ._transaction()
+        .run(|tx| async move {
+            // Locks the project until the attachment is recorded, so concurrent uploads see each other's size
+            tx.project()
+                .update_many(vec![project::id::equals(project_id as i32)], vec![project::id::set(project_id as i32)])
+                .exec()
+                .await?;
+            let used: u64 = tx
+                .attachment()
+                .find_many(vec![attachment::task::is(vec![task::project_id::equals(project_id as i32)])])
+                .exec()
+                .await?
+                .iter()
+                .map(|attachment| attachment.size as u64)
+                .sum();
+            if used + size > project_quota {
+                return Ok(Err(used));
+            }
+            tx.attachment()
+                .create(
+                    task::id::equals(task_id as i32),
+                    name,
+                    size as i32,
+                    mime_type,
+                    checksum,
+                    key,
+                    vec![attachment::uploader::connect(user::id::equals(user_id as i32))],
+                )
+                .with(attachment::uploader::fetch())
+                .exec()
+                .await
+                .map(Ok)
+        })
+        .await
+        .map_err(|err: QueryError| {
+            log::error!(target: LOG_TAG, "Failed to create attachment: {:?}", err);
+            err.to_string()
+        });
+
+    let err = match attachment {
+        Ok(Ok(attachment)) => return Ok(attachment_to_response(&attachment)),
+        Ok(Err(used)) => quota_exceeded(settings, used),
+        Err(err) => AttachmentUploadError::Failed(err),
+    };
+    storage.delete(&storage_key).await.ok();
+    Err(err)
+}
+
+/// Returns an attachment and the storage key of its content if the user participates in its project.
+pub async fn get_attachment_file(user_id: u64, attachment_id: u64) -> Result<Option<(SelectAttachment, String)>, String> {
+    let client = create_prisma_client().await?;
+    let attachment = match client
+        .attachment()
+        .find_unique(attachment::id::equals(attachment_id as i32))
+        .with(attachment::uploader::fetch())
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(attachment) => attachment,
+        None => return Ok(None),
+    };
+    check_member_from_task(user_id, attachment.task_id as u64).await?;
+
+    Ok(Some((attachment_to_response(&attachment), attachment.storage_key.clone())))
+}
+
+/// Deletes an attachment. Allowed to its uploader and the project owner.
+pub async fn delete_attachment(
+    storage: &dyn Storage,
+    user_id: u64,
+    task_id: u64,
+    attachment_id: u64,
+) -> Result<Option<()>, String> {
+    let client = create_prisma_client().await?;
+    let attachment = match client
+        .attachment()
+        .find_unique(attachment::id::equals(attachment_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(attachment) if attachment.task_id as u64 == task_id => attachment,
+        _ => return Ok(None),
+    };
+    check_member_from_task(user_id, task_id).await?;
+
+    let is_uploader = attachment.uploader_id == Some(user_id as i32);
+    if !is_uploader && !is_project_owner(user_id, get_task_project_id(task_id).await?).await? {
+        return Err("Only the uploader or the project owner can delete the attachment".to_string());
+    }
+
+    client
+        .attachment()
+        .delete(attachment::id::equals(attachment_id as i32))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to delete attachment: {:?}", err);
+            err.to_string()
+        })?;
+
+    if let Err(err) = storage.delete(&attachment.storage_key).await {
+        log::error!(target: LOG_TAG, "Failed to remove attachment file {}: {:?}", attachment.storage_key, err);
+    }
+    Ok(Some(()))
+}
@@ -0,0 +1,268 @@
+use std::io::Cursor;
+
+use chrono::{DateTime, Utc};
+use image::{imageops::FilterType, DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits, Rgb, RgbImage};
+use sha2::{Digest, Sha256};
+use strum_macros::{Display, EnumString};
+
+use crate::prisma::{project, user};
+use crate::services::common::create_prisma_client;
+use crate::storage::Storage;
+
+const LOG_TAG: &'static str = "AvatarService";
+/// Sizes in pixels the avatars are stored at, smallest first.
+pub const AVATAR_SIZES: [u32; 3] = [32, 64, 256];
+/// Larger uploads are rejected before decoding to keep memory use bounded.
+const MAX_SOURCE_DIMENSION: u32 = 8192;
+/// Background colors of generated avatars, picked by the owner's id.
+const FALLBACK_COLORS: [(u8, u8, u8); 12] = [
+    (0xE5, 0x73, 0x73),
+    (0xF0, 0x62, 0x92),
+    (0xBA, 0x68, 0xC8),
+    (0x95, 0x75, 0xCD),
+    (0x79, 0x86, 0xCB),
+    (0x64, 0xB5, 0xF6),
+    (0x4F, 0xC3, 0xF7),
+    (0x4D, 0xB6, 0xAC),
+    (0x81, 0xC7, 0x84),
+    (0xAE, 0xD5, 0x81),
+    (0xFF, 0xB7, 0x4D),
+    (0xA1, 0x88, 0x7F),
+];
+const IDENTICON_BACKGROUND: Rgb<u8> = Rgb([0xF0, 0xF0, 0xF0]);
+const IDENTICON_GRID: u32 = 5;
+
+/// Format of a generated avatar: initials as SVG or an identicon as PNG.
+#[derive(Debug, Clone, Copy, PartialEq, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum FallbackFormat {
+    Svg,
+    Png,
+}
+
+/// What a generated avatar is derived from.
+pub struct FallbackSource {
+    /// Distinguishes users and projects with the same id
+    pub kind: &'static str,
+    pub id: u64,
+    pub initials: String,
+}
+
+impl FallbackSource {
+    fn digest(&self) -> [u8; 32] {
+        Sha256::digest(format!("{}-{}", self.kind, self.id)).into()
+    }
+
+    fn color(&self) -> (u8, u8, u8) {
+        FALLBACK_COLORS[self.digest()[0] as usize % FALLBACK_COLORS.len()]
+    }
+
+    /// Changes whenever the rendered image would, so it can be used in an ETag.
+    pub fn version(&self, size: u32, format: FallbackFormat) -> String {
+        let initials = hex::encode(Sha256::digest(self.initials.as_bytes()));
+        format!("{}-{}-{}-{}-{}", self.kind, self.id, size, format, &initials[..8])
+    }
+}
+
+/// First letters of the first and last words, e.g. "Ivan Petrov" becomes "IP".
+pub fn initials<'a>(words: impl IntoIterator<Item = &'a str>) -> String {
+    let letters: Vec<char> = words
+        .into_iter()
+        .flat_map(|text| text.split_whitespace())
+        .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()))
+        .collect();
+    let initials: String = match letters.as_slice() {
+        [] => "?".to_string(),
+        [single] => single.to_string(),
+        [first, .., last] => [*first, *last].iter().collect(),
+    };
+    initials.to_uppercase()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn render_initials_svg(source: &FallbackSource, size: u32) -> Vec<u8> {
+    let (r, g, b) = source.color();
+    format!(
+        concat!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 100 100">"##,
+            r##"<rect width="100" height="100" fill="#{r:02X}{g:02X}{b:02X}"/>"##,
+            r##"<text x="50" y="50" dy=".35em" text-anchor="middle" fill="#FFFFFF" "##,
+            r##"font-family="Helvetica, Arial, sans-serif" font-size="42" font-weight="600">{initials}</text>"##,
+            "</svg>"
+        ),
+        size = size,
+        r = r,
+        g = g,
+        b = b,
+        initials = escape_xml(&source.initials)
+    )
+    .into_bytes()
+}
+
+/// Renders a symmetric 5x5 identicon, as text needs a font to be drawn into a PNG.
+fn render_identicon_png(source: &FallbackSource, size: u32) -> Result<Vec<u8>, String> {
+    let digest = source.digest();
+    let (r, g, b) = source.color();
+    let foreground = Rgb([r, g, b]);
+    let half = (IDENTICON_GRID + 1) / 2;
+    let is_filled = |row: u32, column: u32| {
+        // Mirror the left half so the pattern is symmetric
+        let column = column.min(IDENTICON_GRID - 1 - column);
+        let bit = (row * half + column) as usize;
+        digest[1 + bit / 8] & (1 << (bit % 8)) != 0
+    };
+
+    let padding = size / 12;
+    let inner = size - 2 * padding;
+    let image = RgbImage::from_fn(size, size, |x, y| {
+        if x < padding || y < padding || x >= padding + inner || y >= padding + inner {
+            return IDENTICON_BACKGROUND;
+        }
+        let column = (x - padding) * IDENTICON_GRID / inner;
+        let row = (y - padding) * IDENTICON_GRID / inner;
+        if is_filled(row, column) { foreground } else { IDENTICON_BACKGROUND }
+    });
+
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|err| err.to_string())?;
+    Ok(png)
+}
+
+/// Renders a generated avatar and returns it with its content type.
+pub fn render_fallback(source: &FallbackSource, size: u32, format: FallbackFormat) -> Result<(Vec<u8>, &'static str), String> {
+    match format {
+        FallbackFormat::Svg => Ok((render_initials_svg(source, size), "image/svg+xml")),
+        FallbackFormat::Png => Ok((render_identicon_png(source, size)?, "image/png")),
+    }
+}
+
+pub fn avatar_key(user_id: u64, size: u32) -> String {
+    format!("avatars/{}/{}.png", user_id, size)
+}
+
+/// Picks the smallest stored size that is at least as large as the requested one.
+pub fn avatar_size(requested: Option<u32>) -> u32 {
+    let largest = AVATAR_SIZES[AVATAR_SIZES.len() - 1];
+    match requested {
+        Some(requested) => AVATAR_SIZES.into_iter().find(|size| *size >= requested).unwrap_or(largest),
+        None => largest,
+    }
+}
+
+fn decode_image(data: &[u8]) -> Result<DynamicImage, String> {
+    let mut reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|err| err.to_string())?;
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(|_| "File is not a supported image".to_string())?;
+    // Re-encoding drops EXIF, so the orientation it records has to be applied to the pixels
+    let orientation = decoder.orientation().map_err(|err| err.to_string())?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|_| "File is not a supported image".to_string())?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// Decodes an uploaded image, crops it to a centered square and encodes it as PNG at every avatar size.
+pub fn render_avatar(data: &[u8]) -> Result<Vec<(u32, Vec<u8>)>, String> {
+    let image = decode_image(data)?;
+    let side = image.width().min(image.height());
+    let square = image.crop_imm((image.width() - side) / 2, (image.height() - side) / 2, side, side);
+
+    AVATAR_SIZES
+        .into_iter()
+        .map(|size| {
+            let mut png = Vec::new();
+            square
+                .resize_exact(size, size, FilterType::Lanczos3)
+                .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+                .map_err(|err| err.to_string())?;
+            Ok((size, png))
+        })
+        .collect()
+}
+
+/// Returns when the user last changed their avatar, `None` if they have no custom one,
+/// along with what their generated avatar is derived from.
+pub async fn get_user_avatar(user_id: u64) -> Result<(Option<DateTime<Utc>>, FallbackSource), String> {
+    let client = create_prisma_client().await?;
+    let user = client
+        .user()
+        .find_unique(user::id::equals(user_id as i32))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get user: {:?}", err);
+            err.to_string()
+        })?;
+    let source = FallbackSource {
+        kind: "user",
+        id: user_id,
+        initials: match &user {
+            Some(user) => initials([user.first_name.as_str(), user.last_name.as_str()]),
+            None => initials(std::iter::empty()),
+        },
+    };
+    Ok((user.and_then(|user| user.avatar_updated_at).map(|date| date.with_timezone(&Utc)), source))
+}
+
+pub async fn get_project_icon(project_id: u64) -> Result<Option<FallbackSource>, String> {
+    let client = create_prisma_client().await?;
+    let project = client
+        .project()
+        .find_unique(project::id::equals(project_id as i32))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(project.map(|project| FallbackSource {
+        kind: "project",
+        id: project_id,
+        initials: initials([project.name.as_str()]),
+    }))
+}
+
+async fn set_avatar_updated_at(user_id: u64, updated_at: Option<DateTime<Utc>>) -> Result<(), String> {
+    let client = create_prisma_client().await?;
+    client
+        .user()
+        .update(
+            user::id::equals(user_id as i32),
+            vec![user::avatar_updated_at::set(updated_at.map(|date| date.into()))],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to update avatar: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(())
+}
+
+pub async fn save_avatar(storage: &dyn Storage, user_id: u64, renditions: Vec<(u32, Vec<u8>)>) -> Result<(), String> {
+    for (size, png) in renditions {
+        storage.put(&avatar_key(user_id, size), png, "image/png").await
+            .map_err(|err| format!("{:#}", err))?;
+    }
+    set_avatar_updated_at(user_id, Some(Utc::now())).await
+}
+
+pub async fn delete_avatar(storage: &dyn Storage, user_id: u64) -> Result<(), String> {
+    set_avatar_updated_at(user_id, None).await?;
+    for size in AVATAR_SIZES {
+        if let Err(err) = storage.delete(&avatar_key(user_id, size)).await {
+            log::error!(target: LOG_TAG, "Failed to delete avatar of user {user_id}: {:#}", err);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use prisma_client_rust::{raw, Direction, PrismaValue, QueryError};
+
+use crate::models::actor::Actor;
+use crate::models::board::{BoardEvent, MoveTaskRequest, SelectBoard, SelectBoardColumn};
+use crate::models::task::SelectTask;
+use crate::prisma::{project, task, PrismaClient};
+use crate::services::common::{create_prisma_client, VersionedUpdateError};
+use crate::services::history::record_task_changes;
+use crate::services::task::{
+    check_member_from_task, complete_subtasks, get_task_by_id, open_subtasks_to_complete, record_completed_subtasks,
+    require_project_participant, task_data_to_response, updated_by_params,
+};
+use crate::services::workflow::{check_transition, get_project_statuses, project_status_to_response};
+
+const LOG_TAG: &'static str = "BoardService";
+/// Gap left between neighbours when tasks are appended or a column is renumbered.
+const POSITION_STEP: f64 = 1024.0;
+/// Below this gap a midpoint would lose precision, so the column is renumbered first.
+const MIN_POSITION_GAP: f64 = 1e-6;
+/// Postgres channel carrying task moves to the board viewers of every replica.
+pub const BOARD_EVENTS_CHANNEL: &'static str = "board_events";
+
+/// Sends a task move to the viewers of its board, whichever replica they are connected to.
+pub async fn publish_board_event(event: &BoardEvent) -> Result<(), String> {
+    let payload = serde_json::to_string(event).map_err(|err| err.to_string())?;
+    let client = create_prisma_client().await?;
+    client
+        ._execute_raw(raw!(
+            "SELECT pg_notify({}, {})",
+            PrismaValue::String(BOARD_EVENTS_CHANNEL.to_string()),
+            PrismaValue::String(payload)
+        ))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to publish board event: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(())
+}
+
+pub async fn get_board(user_id: u64, project_id: u64) -> Result<SelectBoard, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let statuses = get_project_statuses(project_id).await?;
+    let client = create_prisma_client().await?;
+    let tasks = client
+        .task()
+        .find_many(vec![task::project_id::equals(project_id as i32)])
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
+        .order_by(task::board_position::order(Direction::Asc))
+        .order_by(task::id::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get board tasks: {:?}", err);
+            err.to_string()
+        })?;
+
+    let mut columns: HashMap<String, Vec<SelectTask>> = HashMap::new();
+    for task in &tasks {
+        columns.entry(task.status.clone()).or_default().push(task_data_to_response(task).await?);
+    }
+    Ok(SelectBoard {
+        project_id,
+        columns: statuses
+            .iter()
+            .map(|status| SelectBoardColumn {
+                status: project_status_to_response(status),
+                tasks: columns.remove(&status.key).unwrap_or_default(),
+            })
+            .collect(),
+    })
+}
+
+async fn get_column(client: &PrismaClient, project_id: i32, status: &str, excluded_id: i32) -> Result<Vec<task::Data>, QueryError> {
+    client
+        .task()
+        .find_many(vec![
+            task::project_id::equals(project_id),
+            task::status::equals(status.to_string()),
+            task::id::not(excluded_id),
+        ])
+        .order_by(task::board_position::order(Direction::Asc))
+        .order_by(task::id::order(Direction::Asc))
+        .exec()
+        .await
+}
+
+/// Position at the bottom of a status column.
+pub async fn next_board_position(client: &PrismaClient, project_id: u64, status: &str) -> Result<f64, QueryError> {
+    let last = client
+        .task()
+        .find_first(vec![
+            task::project_id::equals(project_id as i32),
+            task::status::equals(status.to_string()),
+        ])
+        .order_by(task::board_position::order(Direction::Desc))
+        .exec()
+        .await?;
+    Ok(last.map_or(POSITION_STEP, |task| task.board_position + POSITION_STEP))
+}
+
+/// Position between the task `after_id` and its successor in a column, or at the top without `after_id`.
+/// Returns `None` when the neighbours are too close to fit another task between them.
+async fn place_in_column(
+    client: &PrismaClient,
+    project_id: i32,
+    status: &str,
+    task_id: i32,
+    after_id: Option<i32>,
+) -> Result<Option<f64>, QueryError> {
+    let column = get_column(client, project_id, status, task_id).await?;
+    let index = after_id.and_then(|after_id| column.iter().position(|task| task.id == after_id));
+    let (previous, next) = match index {
+        Some(index) => (Some(column[index].board_position), column.get(index + 1).map(|task| task.board_position)),
+        None => (None, column.first().map(|task| task.board_position)),
+    };
+    Ok(match (previous, next) {
+        (None, None) => Some(POSITION_STEP),
+        (Some(previous), None) => Some(previous + POSITION_STEP),
+        (None, Some(next)) => Some(next - POSITION_STEP),
+        (Some(previous), Some(next)) if next - previous > MIN_POSITION_GAP => Some((previous + next) / 2.0),
+        _ => None,
+    })
+}
+
+/// Spreads the tasks of a column evenly, keeping their order.
+async fn renumber_column(client: &PrismaClient, project_id: i32, status: &str, excluded_id: i32) -> Result<(), QueryError> {
+    let column = get_column(client, project_id, status, excluded_id).await?;
+    for (index, task) in column.iter().enumerate() {
+        client
+            .task()
+            .update(
+                task::id::equals(task.id),
+                vec![task::board_position::set((index + 1) as f64 * POSITION_STEP)],
+            )
+            .exec()
+            .await?;
+    }
+    Ok(())
+}
+
+/// Moves a task to a place in a status column, changing its status and position in one transaction.
+/// With `expected_version` the move only applies to that version of the task.
+pub async fn move_task_on_board(
+    user_id: u64,
+    task_id: u64,
+    request: &MoveTaskRequest,
+    expected_version: Option<u64>,
+) -> Result<Option<SelectTask>, VersionedUpdateError> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let moved = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(task) => task,
+        None => return Ok(None),
+    };
+    if expected_version.map_or(false, |version| version != moved.version as u64) {
+        return Err(VersionedUpdateError::Changed);
+    }
+    check_transition(moved.project_id as u64, &moved.status, &request.status).await?;
+    let statuses = get_project_statuses(moved.project_id as u64).await?;
+    let actor = Actor::User(user_id);
+    let open_subtasks = open_subtasks_to_complete(
+        &client,
+        task_id,
+        &statuses,
+        &request.status,
+        request.complete_subtasks.unwrap_or(false),
+    ).await?;
+    let completed_subtasks = open_subtasks.clone();
+
+    if let Some(after_id) = request.after_id {
+        if after_id == task_id {
+            return Err("A task can't be placed after itself".to_string().into());
+        }
+        let after = client
+            .task()
+            .find_unique(task::id::equals(after_id as i32))
+            .exec()
+            .await
+            .map_err(|err| err.to_string())?;
+        match after {
+            Some(after) if after.project_id == moved.project_id && after.status == request.status => {}
+            _ => return Err(format!("Task {} is not in the {} column", after_id, request.status).into()),
+        }
+    }
+
+    let mut update_properties = updated_by_params(&actor);
+    if moved.status != request.status {
+        update_properties.push(task::status::set(request.status.clone()));
+        // A status set by hand replaces the one an automatic block would restore
+        update_properties.push(task::status_before_block::set(None));
+    }
+    let project_id = moved.project_id;
+    let status = request.status.clone();
+    let after_id = request.after_id.map(|id| id as i32);
+    let updated = client
+        ._transaction()
+        .run(|tx| async move {
+            // Locks the task until the move commits, so a concurrent update based on the same version misses it
+            if let Some(version) = expected_version {
+                let claimed = tx
+                    .task()
+                    .update_many(
+                        vec![task::id::equals(task_id as i32), task::version::equals(version as i32)],
+                        vec![task::version::set(version as i32)],
+                    )
+                    .exec()
+                    .await?;
+                if claimed == 0 {
+                    return Ok(None);
+                }
+            }
+            complete_subtasks(&tx, &actor, &completed_subtasks, &status).await?;
+            let position = match place_in_column(&tx, project_id, &status, task_id as i32, after_id).await? {
+                Some(position) => position,
+                None => {
+                    renumber_column(&tx, project_id, &status, task_id as i32).await?;
+                    place_in_column(&tx, project_id, &status, task_id as i32, after_id).await?
+                        .unwrap_or(POSITION_STEP)
+                }
+            };
+            update_properties.push(task::board_position::set(position));
+            tx.task()
+                .update(task::id::equals(task_id as i32), update_properties)
+                .exec()
+                .await
+                .map(Some)
+        })
+        .await
+        .map_err(|err: QueryError| {
+            log::error!(target: LOG_TAG, "Failed to move task {task_id}: {:?}", err);
+            err.to_string()
+        })?
+        .ok_or(VersionedUpdateError::Changed)?;
+    record_completed_subtasks(&actor, &open_subtasks, &updated.status).await;
+    record_task_changes(&actor, &moved, &updated).await;
+
+    Ok(get_task_by_id(task_id).await?)
+}
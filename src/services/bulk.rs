@@ -0,0 +1,346 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::DateTime;
+use prisma_client_rust::QueryError;
+
+use crate::mailer::mailer::Mailer;
+use crate::models::actor::Actor;
+use crate::models::bulk::{BulkTaskRequest, SelectBulkTaskResponse, SelectBulkTaskResult};
+use crate::models::workflow::StatusCategory;
+use crate::prisma::{label, project_status, task, user};
+use crate::services::board::next_board_position;
+use crate::services::common::create_prisma_client;
+use crate::services::dependency::{collect_status_change, get_blocked_task_ids};
+use crate::services::history::record_task_changes;
+use crate::services::label::require_project_labels;
+use crate::services::notifications::create_notification;
+use crate::services::task::{collect_descendants, delete_keeping_subtasks, get_task_by_id, updated_by_params};
+use crate::services::user::{is_project_member, is_project_owner};
+use crate::services::watcher::WatcherDigest;
+use crate::services::workflow::{check_transition, get_project_statuses, status_category};
+
+const LOG_TAG: &'static str = "BulkService";
+
+/// Checks made once per project and user while a request is validated.
+#[derive(Default)]
+struct ProjectCache {
+    participants: HashMap<(u64, u64), bool>,
+    statuses: HashMap<u64, Vec<project_status::Data>>,
+}
+
+impl ProjectCache {
+    async fn is_participant(&mut self, user_id: u64, project_id: u64) -> Result<bool, String> {
+        if let Some(participant) = self.participants.get(&(user_id, project_id)) {
+            return Ok(*participant);
+        }
+        let participant = is_project_member(user_id, project_id).await? || is_project_owner(user_id, project_id).await?;
+        self.participants.insert((user_id, project_id), participant);
+        Ok(participant)
+    }
+
+    async fn statuses(&mut self, project_id: u64) -> Result<&[project_status::Data], String> {
+        if !self.statuses.contains_key(&project_id) {
+            let statuses = get_project_statuses(project_id).await?;
+            self.statuses.insert(project_id, statuses);
+        }
+        Ok(&self.statuses[&project_id])
+    }
+}
+
+/// Checks that the user may apply the request to a task.
+async fn validate_item(
+    cache: &mut ProjectCache,
+    user_id: u64,
+    task: &task::Data,
+    request: &BulkTaskRequest,
+) -> Result<(), String> {
+    let project_id = task.project_id as u64;
+    if !cache.is_participant(user_id, project_id).await? {
+        return Err("User is not a member or owner of the project".to_string());
+    }
+    if request.delete.unwrap_or(false) {
+        return Ok(());
+    }
+
+    if let Some(status) = &request.status {
+        check_transition(project_id, &task.status, status).await?;
+    }
+    for assignee_id in &request.add_assignee_ids {
+        if !cache.is_participant(*assignee_id, project_id).await? {
+            return Err(format!("User {} is not a member or owner of the project", assignee_id));
+        }
+    }
+    require_project_labels(project_id, &request.add_label_ids).await
+}
+
+/// Drops the tasks that would be completed with open subtasks. Subtasks completed in the same request
+/// don't count as left open, but only when their own change is valid, which in turn depends on their
+/// subtasks, so the check repeats until no more tasks drop out.
+async fn drop_tasks_with_open_subtasks(
+    cache: &mut ProjectCache,
+    status: &str,
+    valid: &mut Vec<task::Data>,
+    errors: &mut HashMap<u64, String>,
+) -> Result<(), String> {
+    let client = create_prisma_client().await?;
+    let mut open_subtasks: HashMap<i32, Vec<i32>> = HashMap::new();
+    for task in valid.iter() {
+        let statuses = cache.statuses(task.project_id as u64).await?;
+        if status_category(statuses, status) != StatusCategory::Done {
+            continue;
+        }
+        let subtask_ids = collect_descendants(&client, task.id as u64).await
+            .map_err(|err| err.to_string())?
+            .iter()
+            .filter(|subtask| !status_category(statuses, &subtask.status).is_finished())
+            .map(|subtask| subtask.id)
+            .collect();
+        open_subtasks.insert(task.id, subtask_ids);
+    }
+
+    loop {
+        let passed: HashSet<i32> = valid.iter().map(|task| task.id).collect();
+        let mut dropped: Vec<(i32, usize)> = vec![];
+        for (task_id, subtask_ids) in &open_subtasks {
+            let left_open = subtask_ids.iter().filter(|id| !passed.contains(id)).count();
+            if passed.contains(task_id) && left_open > 0 {
+                dropped.push((*task_id, left_open));
+            }
+        }
+        if dropped.is_empty() {
+            return Ok(());
+        }
+        for (task_id, left_open) in dropped {
+            valid.retain(|task| task.id != task_id);
+            errors.insert(task_id as u64, format!("Task has {} open subtasks", left_open));
+        }
+    }
+}
+
+/// `current` with `added` ids and without `removed` ones.
+fn apply_id_changes(current: &[i32], added: &[u64], removed: &[u64]) -> Vec<i32> {
+    let mut ids: Vec<i32> = current.to_vec();
+    ids.extend(added.iter().map(|id| *id as i32));
+    ids.retain(|id| !removed.contains(&(*id as u64)));
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// One notification per user listing the tasks they were assigned to or removed from.
+async fn notify_assignment_changes(mailer: &Mailer, changes: &[(task::Data, task::Data)]) {
+    let mut assigned: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+    let mut removed: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+    for (before, after) in changes {
+        let before_ids: HashSet<i32> = before.attached_to.iter().flatten().map(|user| user.id).collect();
+        let after_ids: HashSet<i32> = after.attached_to.iter().flatten().map(|user| user.id).collect();
+        for user_id in after_ids.difference(&before_ids) {
+            assigned.entry(*user_id).or_default().push(after.name.clone());
+        }
+        for user_id in before_ids.difference(&after_ids) {
+            removed.entry(*user_id).or_default().push(after.name.clone());
+        }
+    }
+
+    let recipients: HashSet<i32> = assigned.keys().chain(removed.keys()).copied().collect();
+    for user_id in recipients {
+        let mut parts = vec![];
+        if let Some(tasks) = assigned.get(&user_id) {
+            parts.push(format!("Вы были назначены на задачи: {}.", tasks.join(", ")));
+        }
+        if let Some(tasks) = removed.get(&user_id) {
+            parts.push(format!("Вас удалили с задач: {}.", tasks.join(", ")));
+        }
+        create_notification("Изменения в задачах".to_string(), parts.join(" "), user_id as u64, mailer).await;
+    }
+}
+
+/// Applies the same changes to several tasks. Every task is authorized and validated on its own
+/// and reported in the results, the valid ones are changed in a single transaction.
+/// Watchers of tasks blocked or unblocked by the changes are added to `digest`.
+pub async fn bulk_update_tasks(
+    mailer: &Mailer,
+    digest: &mut WatcherDigest,
+    user_id: u64,
+    request: &BulkTaskRequest,
+) -> Result<SelectBulkTaskResponse, String> {
+    let delete = request.delete.unwrap_or(false);
+    let has_changes = request.status.is_some()
+        || !request.add_assignee_ids.is_empty()
+        || !request.remove_assignee_ids.is_empty()
+        || !request.add_label_ids.is_empty()
+        || !request.remove_label_ids.is_empty()
+        || request.due_date.is_some()
+        || request.clear_due_date.unwrap_or(false);
+    if delete && has_changes {
+        return Err("Deleting tasks can't be combined with other changes".to_string());
+    }
+    if !delete && !has_changes {
+        return Err("No changes requested".to_string());
+    }
+    if request.due_date.is_some() && request.clear_due_date.unwrap_or(false) {
+        return Err("Set either due_date or clear_due_date".to_string());
+    }
+    let due_date = match request.due_date {
+        Some(due_date) => Some(DateTime::from_timestamp(due_date as i64, 0).ok_or_else(|| "Invalid due date".to_string())?),
+        None => None,
+    };
+
+    let mut task_ids: Vec<u64> = vec![];
+    for task_id in &request.task_ids {
+        if !task_ids.contains(task_id) {
+            task_ids.push(*task_id);
+        }
+    }
+
+    let client = create_prisma_client().await?;
+    let tasks: HashMap<u64, task::Data> = client
+        .task()
+        .find_many(vec![task::id::in_vec(task_ids.iter().map(|id| *id as i32).collect())])
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .map(|task| (task.id as u64, task))
+        .collect();
+
+    let mut errors: HashMap<u64, String> = HashMap::new();
+    let mut valid: Vec<task::Data> = vec![];
+    let mut cache = ProjectCache::default();
+    for task_id in &task_ids {
+        let task = match tasks.get(task_id) {
+            Some(task) => task,
+            None => {
+                errors.insert(*task_id, "Task not found".to_string());
+                continue;
+            }
+        };
+        match validate_item(&mut cache, user_id, task, request).await {
+            Ok(()) => valid.push(task.clone()),
+            Err(err) => {
+                errors.insert(*task_id, err);
+            }
+        }
+    }
+    if let Some(status) = &request.status {
+        drop_tasks_with_open_subtasks(&mut cache, status, &mut valid, &mut errors).await?;
+    }
+
+    // Links of deleted tasks are gone afterwards, so the tasks they block are collected first
+    let mut blocked_task_ids: HashMap<u64, Vec<u64>> = HashMap::new();
+    if delete || request.status.is_some() {
+        for task in &valid {
+            blocked_task_ids.insert(task.id as u64, get_blocked_task_ids(task.id as u64).await?);
+        }
+    }
+
+    let mut changes: Vec<(task::Data, task::Data)> = vec![];
+    let mut applied = false;
+    if !valid.is_empty() {
+        let befores = valid.clone();
+        let status = request.status.clone();
+        let add_assignee_ids = request.add_assignee_ids.clone();
+        let remove_assignee_ids = request.remove_assignee_ids.clone();
+        let add_label_ids = request.add_label_ids.clone();
+        let remove_label_ids = request.remove_label_ids.clone();
+        let clear_due_date = request.clear_due_date.unwrap_or(false);
+        let result = client
+            ._transaction()
+            .run(|tx| async move {
+                let mut updated = vec![];
+                for task in befores {
+                    if delete {
+                        delete_keeping_subtasks(&tx, task.id).await?;
+                        continue;
+                    }
+
+                    let mut update_properties = updated_by_params(&Actor::User(user_id));
+                    if let Some(status) = status.clone().filter(|status| *status != task.status) {
+                        let board_position = next_board_position(&tx, task.project_id as u64, &status).await?;
+                        update_properties.push(task::board_position::set(board_position));
+                        update_properties.push(task::status::set(status));
+                        update_properties.push(task::status_before_block::set(None));
+                    }
+                    if !add_assignee_ids.is_empty() || !remove_assignee_ids.is_empty() {
+                        let current: Vec<i32> = task.attached_to.iter().flatten().map(|user| user.id).collect();
+                        let assignee_ids = apply_id_changes(&current, &add_assignee_ids, &remove_assignee_ids);
+                        update_properties.push(task::attached_to::set(assignee_ids.into_iter().map(user::id::equals).collect()));
+                        update_properties.push(task::watchers::connect(
+                            add_assignee_ids.iter().map(|id| user::id::equals(*id as i32)).collect(),
+                        ));
+                    }
+                    if !add_label_ids.is_empty() || !remove_label_ids.is_empty() {
+                        let current: Vec<i32> = task.labels.iter().flatten().map(|label| label.id).collect();
+                        let label_ids = apply_id_changes(&current, &add_label_ids, &remove_label_ids);
+                        update_properties.push(task::labels::set(label_ids.into_iter().map(label::id::equals).collect()));
+                    }
+                    if let Some(due_date) = due_date {
+                        update_properties.push(task::due_date::set(Some(due_date.into())));
+                    } else if clear_due_date {
+                        update_properties.push(task::due_date::set(None));
+                    }
+
+                    let after = tx
+                        .task()
+                        .update(task::id::equals(task.id), update_properties)
+                        .with(task::attached_to::fetch(vec![]))
+                        .with(task::labels::fetch(vec![]))
+                        .exec()
+                        .await?;
+                    updated.push((task, after));
+                }
+                Ok(updated)
+            })
+            .await
+            .map_err(|err: QueryError| {
+                log::error!(target: LOG_TAG, "Failed to apply bulk changes: {:?}", err);
+                err.to_string()
+            });
+        match result {
+            Ok(updated) => {
+                changes = updated;
+                applied = true;
+            }
+            // Nothing was changed, so every task that passed the checks failed along with the transaction
+            Err(err) => {
+                for task in &valid {
+                    errors.insert(task.id as u64, err.clone());
+                }
+            }
+        }
+    }
+
+    let actor = Actor::User(user_id);
+    if applied {
+        for (before, after) in &changes {
+            record_task_changes(&actor, before, after).await;
+        }
+        notify_assignment_changes(mailer, &changes).await;
+        for task in &valid {
+            let status_changed = changes.iter()
+                .any(|(before, after)| before.id == task.id && before.status != after.status);
+            if delete || status_changed {
+                let blocked = blocked_task_ids.get(&(task.id as u64)).cloned().unwrap_or_default();
+                collect_status_change(digest, &actor, task.id as u64, &blocked).await;
+            }
+        }
+    }
+
+    let mut results = vec![];
+    for task_id in task_ids {
+        let result = match errors.remove(&task_id) {
+            Some(error) => SelectBulkTaskResult { task_id, success: false, error: Some(error), task: None },
+            None if delete => SelectBulkTaskResult { task_id, success: true, error: None, task: None },
+            None => SelectBulkTaskResult { task_id, success: true, error: None, task: get_task_by_id(task_id).await? },
+        };
+        results.push(result);
+    }
+    let succeeded = results.iter().filter(|result| result.success).count() as u64;
+    Ok(SelectBulkTaskResponse {
+        failed: results.len() as u64 - succeeded,
+        succeeded,
+        results,
+    })
+}
@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use prisma_client_rust::{or, Direction};
+
+use crate::models::comment::{
+    CreateCommentRequest, SelectComment, SelectCommentPage, SelectCommentsRequest, UpdateCommentRequest,
+};
+use crate::prisma::{comment, project, task, user};
+use crate::services::common::create_prisma_client;
+use crate::services::task::check_member_from_task;
+use crate::services::user::user_data_to_response;
+use crate::services::watcher::add_task_watchers;
+
+const LOG_TAG: &'static str = "CommentService";
+const DEFAULT_PAGE_SIZE: u64 = 50;
+
+pub fn comment_to_response(comment: &comment::Data) -> Result<SelectComment, String> {
+    let author = match &comment.author {
+        Some(author) => user_data_to_response(author),
+        None => return Err("Failed to fetch comment author".to_string()),
+    };
+    let deleted = comment.deleted_at.is_some();
+    Ok(SelectComment {
+        id: comment.id as u64,
+        task_id: comment.task_id as u64,
+        author,
+        body: if deleted { String::new() } else { comment.body.clone() },
+        created_at: comment.created_at.timestamp() as u64,
+        edited_at: comment.edited_at.map(|date| date.timestamp() as u64),
+        deleted,
+    })
+}
+
+/// Extracts the usernames mentioned as `@username` in a comment.
+fn parse_mentions(body: &str) -> HashSet<String> {
+    let is_username_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-' || c == '.';
+    let mut mentions = HashSet::new();
+    let mut previous = None;
+    for (index, c) in body.char_indices() {
+        // Skip addresses like user@example.com
+        let at_word_start = previous.map_or(true, |p: char| !is_username_char(p));
+        if c == '@' && at_word_start {
+            let rest = &body[index + 1..];
+            let end = rest.find(|c: char| !is_username_char(c)).unwrap_or(rest.len());
+            let username = rest[..end].trim_end_matches('.');
+            if !username.is_empty() {
+                mentions.insert(username.to_string());
+            }
+        }
+        previous = Some(c);
+    }
+    mentions
+}
+
+/// Resolves the mentions of a comment to the ids of project participants.
+async fn resolve_mentions(project_id: u64, body: &str) -> Result<HashSet<u64>, String> {
+    let usernames = parse_mentions(body);
+    if usernames.is_empty() {
+        return Ok(HashSet::new());
+    }
+
+    let client = create_prisma_client().await?;
+    let users = client
+        .user()
+        .find_many(vec![
+            user::username::in_vec(usernames.into_iter().collect()),
+            or![
+                user::projects::some(vec![project::id::equals(project_id as i32)]),
+                user::team_projects::some(vec![project::id::equals(project_id as i32)])
+            ],
+        ])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to resolve mentions: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(users.into_iter().map(|user| user.id as u64).collect())
+}
+
+async fn get_comment(comment_id: u64) -> Result<Option<comment::Data>, String> {
+    let client = create_prisma_client().await?;
+    client
+        .comment()
+        .find_unique(comment::id::equals(comment_id as i32))
+        .with(comment::task::fetch())
+        .exec()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn get_task_comments(
+    user_id: u64,
+    task_id: u64,
+    filters: &SelectCommentsRequest,
+) -> Result<SelectCommentPage, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let page = filters.page.unwrap_or(1);
+    let per_page = filters.per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+
+    let client = create_prisma_client().await?;
+    let total = client
+        .comment()
+        .count(vec![comment::task_id::equals(task_id as i32)])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let comments = client
+        .comment()
+        .find_many(vec![comment::task_id::equals(task_id as i32)])
+        .with(comment::author::fetch())
+        .order_by(comment::created_at::order(Direction::Asc))
+        .order_by(comment::id::order(Direction::Asc))
+        .skip(i64::try_from((page - 1).saturating_mul(per_page)).unwrap_or(i64::MAX))
+        .take(per_page as i64)
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get comments: {:?}", err);
+            err.to_string()
+        })?;
+
+    Ok(SelectCommentPage {
+        items: comments.iter().map(comment_to_response).collect::<Result<_, _>>()?,
+        page,
+        per_page,
+        total: total as u64,
+    })
+}
+
+/// Creates a comment and returns it with the ids of the participants it mentions.
+pub async fn create_comment(
+    user_id: u64,
+    task_id: u64,
+    request: &CreateCommentRequest,
+) -> Result<(SelectComment, HashSet<u64>), String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let comment = client
+        .comment()
+        .create(
+            request.body.clone(),
+            task::id::equals(task_id as i32),
+            user::id::equals(user_id as i32),
+            vec![],
+        )
+        .with(comment::author::fetch())
+        .with(comment::task::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to create comment: {:?}", err);
+            err.to_string()
+        })?;
+
+    // Failures are logged there, the comment itself is saved
+    add_task_watchers(task_id, &[user_id]).await.ok();
+
+    let project_id = comment.task.as_ref().map(|task| task.project_id as u64)
+        .ok_or_else(|| "Failed to fetch comment task".to_string())?;
+    let mut mentions = resolve_mentions(project_id, &request.body).await?;
+    mentions.remove(&user_id);
+
+    Ok((comment_to_response(&comment)?, mentions))
+}
+
+/// Edits a comment of the user and returns it with the ids of the participants
+/// mentioned for the first time by the edit.
+pub async fn update_comment(
+    user_id: u64,
+    task_id: u64,
+    comment_id: u64,
+    request: &UpdateCommentRequest,
+) -> Result<Option<(SelectComment, HashSet<u64>)>, String> {
+    let existing = match get_comment(comment_id).await? {
+        Some(comment) if comment.task_id as u64 == task_id => comment,
+        _ => return Ok(None),
+    };
+    // Authors who left the project can't change what they wrote there anymore
+    check_member_from_task(user_id, task_id).await?;
+    if existing.author_id as u64 != user_id {
+        return Err("Only the author can edit the comment".to_string());
+    }
+    if existing.deleted_at.is_some() {
+        return Err("Comment was deleted".to_string());
+    }
+    let project_id = existing.task.as_ref().map(|task| task.project_id as u64)
+        .ok_or_else(|| "Failed to fetch comment task".to_string())?;
+
+    let client = create_prisma_client().await?;
+    let comment = client
+        .comment()
+        .update(
+            comment::id::equals(comment_id as i32),
+            vec![
+                comment::body::set(request.body.clone()),
+                comment::edited_at::set(Some(Utc::now().into())),
+            ],
+        )
+        .with(comment::author::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to update comment: {:?}", err);
+            err.to_string()
+        })?;
+
+    let previous_mentions = resolve_mentions(project_id, &existing.body).await?;
+    let mut mentions = resolve_mentions(project_id, &request.body).await?;
+    mentions.retain(|id| *id != user_id && !previous_mentions.contains(id));
+
+    Ok(Some((comment_to_response(&comment)?, mentions)))
+}
+
+/// Soft deletes a comment of the user, keeping its place in the discussion.
+pub async fn delete_comment(user_id: u64, task_id: u64, comment_id: u64) -> Result<Option<()>, String> {
+    let existing = match get_comment(comment_id).await? {
+        Some(comment) if comment.task_id as u64 == task_id => comment,
+        _ => return Ok(None),
+    };
+    check_member_from_task(user_id, task_id).await?;
+    if existing.author_id as u64 != user_id {
+        return Err("Only the author can delete the comment".to_string());
+    }
+    if existing.deleted_at.is_some() {
+        return Ok(Some(()));
+    }
+
+    let client = create_prisma_client().await?;
+    client
+        .comment()
+        .update(
+            comment::id::equals(comment_id as i32),
+            vec![comment::deleted_at::set(Some(Utc::now().into()))],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to delete comment: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(Some(()))
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use crate::prisma::PrismaClient;
 
 pub async fn create_prisma_client() -> Result<PrismaClient, String> {
@@ -8,4 +10,27 @@ pub async fn create_prisma_client() -> Result<PrismaClient, String> {
             Err(err.to_string())
         }
     }
-}
\ No newline at end of file
+}
+
+/// Error of an update that only applies to an expected version of a task or project.
+#[derive(Debug)]
+pub enum VersionedUpdateError {
+    /// The resource was changed since the expected version
+    Changed,
+    Failed(String),
+}
+
+impl From<String> for VersionedUpdateError {
+    fn from(err: String) -> Self {
+        VersionedUpdateError::Failed(err)
+    }
+}
+
+impl Display for VersionedUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionedUpdateError::Changed => write!(f, "Resource was changed since the given version"),
+            VersionedUpdateError::Failed(err) => write!(f, "{}", err),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet};
+
+use prisma_client_rust::QueryError;
+use prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation;
+
+use crate::mailer::mailer::Mailer;
+use crate::models::actor::Actor;
+use crate::models::dependency::{SelectLinkedTask, SelectTaskDependencies};
+use crate::models::task::TaskStatus;
+use crate::prisma::{project_status, task, task_dependency};
+use crate::services::board::next_board_position;
+use crate::services::common::create_prisma_client;
+use crate::services::history::record_task_changes;
+use crate::services::task::{check_member_from_task, updated_by_params};
+use crate::services::watcher::{get_watcher_ids, WatcherDigest};
+use crate::services::workflow::{get_project_statuses, status_category};
+
+const LOG_TAG: &'static str = "DependencyService";
+
+fn linked_task_to_response(task: &task::Data, statuses: &[project_status::Data]) -> SelectLinkedTask {
+    SelectLinkedTask {
+        id: task.id as u64,
+        name: task.name.clone(),
+        status: task.status.clone(),
+        status_category: status_category(statuses, &task.status),
+    }
+}
+
+pub async fn get_task_dependencies(user_id: u64, task_id: u64) -> Result<SelectTaskDependencies, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let task = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::blocks::fetch(vec![]).with(task_dependency::blocked::fetch()))
+        .with(task::blocked_by::fetch(vec![]).with(task_dependency::blocker::fetch()))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+
+    let statuses = get_project_statuses(task.project_id as u64).await?;
+    let blocks = task.blocks.unwrap_or_default();
+    let blocked_by = task.blocked_by.unwrap_or_default();
+    Ok(SelectTaskDependencies {
+        blocks: blocks.iter()
+            .filter_map(|link| link.blocked.as_deref())
+            .map(|task| linked_task_to_response(task, &statuses))
+            .collect(),
+        blocked_by: blocked_by.iter()
+            .filter_map(|link| link.blocker.as_deref())
+            .map(|task| linked_task_to_response(task, &statuses))
+            .collect(),
+    })
+}
+
+/// Whether `to` can be reached from `from` by following "blocks" links.
+fn is_reachable(links: &HashMap<i32, Vec<i32>>, from: i32, to: i32) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(current) = stack.pop() {
+        if current == to {
+            return true;
+        }
+        if !visited.insert(current) {
+            continue;
+        }
+        if let Some(next) = links.get(&current) {
+            stack.extend(next.iter().copied());
+        }
+    }
+    false
+}
+
+/// Makes `blocker_id` block `task_id`. Both tasks have to be in the same project
+/// and the link must not close a cycle.
+pub async fn add_dependency(
+    mailer: &Mailer,
+    user_id: u64,
+    task_id: u64,
+    blocker_id: u64,
+) -> Result<SelectTaskDependencies, String> {
+    check_member_from_task(user_id, task_id).await?;
+    if task_id == blocker_id {
+        return Err("A task can't block itself".to_string());
+    }
+
+    let client = create_prisma_client().await?;
+    let tasks = client
+        .task()
+        .find_many(vec![task::id::in_vec(vec![task_id as i32, blocker_id as i32])])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let project_ids: HashSet<i32> = tasks.iter().map(|task| task.project_id).collect();
+    if tasks.len() != 2 {
+        return Err("Task not found".to_string());
+    }
+    if project_ids.len() != 1 {
+        return Err("Tasks belong to different projects".to_string());
+    }
+    let project_id = tasks[0].project_id;
+
+    let project_links = client
+        .task_dependency()
+        .find_many(vec![task_dependency::blocker::is(vec![task::project_id::equals(project_id)])])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut links: HashMap<i32, Vec<i32>> = HashMap::new();
+    for link in &project_links {
+        links.entry(link.blocker_id).or_default().push(link.blocked_id);
+    }
+    if is_reachable(&links, task_id as i32, blocker_id as i32) {
+        return Err("The dependency would create a cycle".to_string());
+    }
+
+    client
+        .task_dependency()
+        .create(
+            task::id::equals(blocker_id as i32),
+            task::id::equals(task_id as i32),
+            vec![],
+        )
+        .exec()
+        .await
+        .map_err(|err: QueryError| {
+            if err.is_prisma_error::<UniqueKeyViolation>() {
+                return "The dependency already exists".to_string();
+            }
+            log::error!(target: LOG_TAG, "Failed to create dependency: {:?}", err);
+            err.to_string()
+        })?;
+
+    refresh_blocked_state(mailer, &Actor::User(user_id), task_id).await?;
+    get_task_dependencies(user_id, task_id).await
+}
+
+pub async fn remove_dependency(
+    mailer: &Mailer,
+    user_id: u64,
+    task_id: u64,
+    blocker_id: u64,
+) -> Result<SelectTaskDependencies, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    client
+        .task_dependency()
+        .delete_many(vec![
+            task_dependency::blocker_id::equals(blocker_id as i32),
+            task_dependency::blocked_id::equals(task_id as i32),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    refresh_blocked_state(mailer, &Actor::User(user_id), task_id).await?;
+    get_task_dependencies(user_id, task_id).await
+}
+
+/// Moves a task to the `blocked` status while any of its blockers is unfinished and back to the status
+/// it had before once all of them are done or cancelled. Assignees and watchers other than the actor are notified of either change.
+/// Projects whose workflow has no `blocked` status only get the links, without the automatic moves.
+pub async fn refresh_blocked_state(mailer: &Mailer, actor: &Actor, task_id: u64) -> Result<(), String> {
+    let mut digest = WatcherDigest::default();
+    let result = collect_blocked_state(&mut digest, actor, task_id).await;
+    digest.send(mailer).await;
+    result
+}
+
+/// Blocks or unblocks a task like `refresh_blocked_state`, adding the notices for its assignees and watchers to a digest.
+async fn collect_blocked_state(digest: &mut WatcherDigest, actor: &Actor, task_id: u64) -> Result<(), String> {
+    let client = create_prisma_client().await?;
+    let task = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::blocked_by::fetch(vec![]).with(task_dependency::blocker::fetch()))
+        .with(task::attached_to::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(task) => task,
+        None => return Ok(()),
+    };
+
+    let statuses = get_project_statuses(task.project_id as u64).await?;
+    let blocked = TaskStatus::Blocked.to_string();
+    if !statuses.iter().any(|status| status.key == blocked) {
+        return Ok(());
+    }
+    let is_finished = |status: &str| status_category(&statuses, status).is_finished();
+
+    let unfinished_blockers: Vec<String> = task.blocked_by.clone().unwrap_or_default()
+        .iter()
+        .filter_map(|link| link.blocker.as_deref())
+        .filter(|blocker| !is_finished(&blocker.status))
+        .map(|blocker| blocker.name.clone())
+        .collect();
+
+    let (mut update_properties, title, description) = if !unfinished_blockers.is_empty() {
+        // Finished tasks and tasks already blocked by hand are left alone
+        if is_finished(&task.status) || task.status == blocked {
+            return Ok(());
+        }
+        let board_position = next_board_position(&client, task.project_id as u64, &blocked).await
+            .map_err(|err| err.to_string())?;
+        (
+            vec![
+                task::status_before_block::set(Some(task.status.clone())),
+                task::status::set(blocked),
+                task::board_position::set(board_position),
+            ],
+            "Задача заблокирована",
+            format!("Задача {} заблокирована, пока не завершены задачи: {}.", task.name, unfinished_blockers.join(", ")),
+        )
+    } else {
+        let previous_status = match (&task.status, &task.status_before_block) {
+            (status, Some(previous_status)) if *status == blocked => previous_status.clone(),
+            _ => return Ok(()),
+        };
+        let board_position = next_board_position(&client, task.project_id as u64, &previous_status).await
+            .map_err(|err| err.to_string())?;
+        (
+            vec![
+                task::status::set(previous_status.clone()),
+                task::status_before_block::set(None),
+                task::board_position::set(board_position),
+            ],
+            "Задача разблокирована",
+            format!("Все задачи, блокировавшие задачу {}, завершены. Статус возвращён в {}.", task.name, previous_status),
+        )
+    };
+    update_properties.extend(updated_by_params(actor));
+
+    let updated = client
+        .task()
+        .update(task::id::equals(task.id), update_properties)
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to update blocked state of task {}: {:?}", task.id, err);
+            err.to_string()
+        })?;
+    record_task_changes(actor, &task, &updated).await;
+
+    // Assignees hear about it even when they don't watch the task
+    let mut recipient_ids = get_watcher_ids(task.id as u64).await?;
+    recipient_ids.extend(task.attached_to.iter().flatten().map(|user| user.id as u64));
+    digest.add_for(actor, recipient_ids, title, description);
+    Ok(())
+}
+
+/// Ids of the tasks a task blocks.
+pub async fn get_blocked_task_ids(task_id: u64) -> Result<Vec<u64>, String> {
+    let client = create_prisma_client().await?;
+    let links = client
+        .task_dependency()
+        .find_many(vec![task_dependency::blocker_id::equals(task_id as i32)])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(links.into_iter().map(|link| link.blocked_id as u64).collect())
+}
+
+/// Re-evaluates a task and everything it blocks after its status changed or it was deleted.
+pub async fn propagate_status_change(mailer: &Mailer, actor: &Actor, task_id: u64, blocked_task_ids: &[u64]) {
+    let mut digest = WatcherDigest::default();
+    collect_status_change(&mut digest, actor, task_id, blocked_task_ids).await;
+    digest.send(mailer).await;
+}
+
+/// Re-evaluates tasks like `propagate_status_change`, adding the notices for their watchers to a digest.
+pub async fn collect_status_change(digest: &mut WatcherDigest, actor: &Actor, task_id: u64, blocked_task_ids: &[u64]) {
+    if let Err(err) = collect_blocked_state(digest, actor, task_id).await {
+        log::error!(target: LOG_TAG, "Failed to refresh blocked state of task {task_id}: {err}");
+    }
+    for blocked_id in blocked_task_ids {
+        if let Err(err) = collect_blocked_state(digest, actor, *blocked_id).await {
+            log::error!(target: LOG_TAG, "Failed to refresh blocked state of task {blocked_id}: {err}");
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use prisma_client_rust::Direction;
+
+use crate::github::providers::{ExternalIssueComment, IssueProviders};
+use crate::models::discussion::{SelectExternalComment, SelectTaskDiscussion};
+use crate::prisma::{external_comment, task};
+use crate::services::common::create_prisma_client;
+use crate::services::project::get_repository_link;
+use crate::services::task::require_project_participant;
+use crate::services::user::get_user;
+use crate::utils::secret::SecretKey;
+
+const LOG_TAG: &'static str = "DiscussionService";
+
+/// A task linked to an issue, with the time its comments were last mirrored.
+pub struct LinkedTask {
+    pub task_id: u64,
+    pub comments_synced_at: Option<DateTime<Utc>>,
+}
+
+pub fn external_comment_to_response(comment: &external_comment::Data) -> SelectExternalComment {
+    SelectExternalComment {
+        id: comment.id as u64,
+        external_id: comment.external_id.clone(),
+        author: comment.author.clone(),
+        author_url: comment.author_url.clone(),
+        body: comment.body.clone(),
+        url: comment.url.clone(),
+        created_at: comment.created_at.timestamp() as u64,
+        updated_at: comment.updated_at.timestamp() as u64,
+        edited: comment.updated_at > comment.created_at,
+    }
+}
+
+pub async fn get_task_discussion(user_id: u64, task_id: u64) -> Result<Option<SelectTaskDiscussion>, String> {
+    let client = create_prisma_client().await?;
+    let task = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::external_comments::fetch(vec![]).order_by(external_comment::created_at::order(Direction::Asc)))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get task discussion: {:?}", err);
+            err.to_string()
+        })? {
+        Some(task) => task,
+        None => return Ok(None),
+    };
+    require_project_participant(user_id, task.project_id as u64).await?;
+
+    let comments = match &task.external_comments {
+        Some(comments) => comments.iter().map(external_comment_to_response).collect(),
+        None => return Err("Failed to fetch task discussion".to_string()),
+    };
+    Ok(Some(SelectTaskDiscussion {
+        task_id,
+        issue: task.assigned_issue.map(|issue| issue as u64),
+        synced_at: task.comments_synced_at.map(|date| date.timestamp() as u64),
+        comments,
+    }))
+}
+
+/// Returns the tasks of a project linked to an issue, keyed by the issue number.
+pub async fn get_linked_tasks(project_id: u64) -> Result<HashMap<u64, LinkedTask>, String> {
+    let client = create_prisma_client().await?;
+    let tasks = client
+        .task()
+        .find_many(vec![
+            task::project_id::equals(project_id as i32),
+            task::assigned_issue::not(None),
+        ])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get linked tasks: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(tasks
+        .into_iter()
+        .filter_map(|task| {
+            task.assigned_issue.map(|issue| {
+                (issue as u64, LinkedTask {
+                    task_id: task.id as u64,
+                    comments_synced_at: task.comments_synced_at.map(|date| date.with_timezone(&Utc)),
+                })
+            })
+        })
+        .collect())
+}
+
+async fn upsert_external_comment(task_id: u64, comment: &ExternalIssueComment) -> Result<external_comment::Data, String> {
+    let client = create_prisma_client().await?;
+    let fields = || {
+        vec![
+            external_comment::author::set(comment.author.clone()),
+            external_comment::author_url::set(comment.author_url.clone()),
+            external_comment::body::set(comment.body.clone()),
+            external_comment::url::set(comment.url.clone()),
+            external_comment::updated_at::set(comment.updated_at.into()),
+        ]
+    };
+    client
+        .external_comment()
+        .upsert(
+            external_comment::task_id_external_id(task_id as i32, comment.id.clone()),
+            external_comment::create(
+                task::id::equals(task_id as i32),
+                comment.id.clone(),
+                comment.author.clone(),
+                comment.body.clone(),
+                comment.created_at.into(),
+                comment.updated_at.into(),
+                vec![
+                    external_comment::author_url::set(comment.author_url.clone()),
+                    external_comment::url::set(comment.url.clone()),
+                ],
+            ),
+            fields(),
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to store external comment: {:?}", err);
+            err.to_string()
+        })
+}
+
+/// Mirrors the comments of an issue into the task's discussion. Comments are matched by their
+/// id in the issue tracker, so edits overwrite the stored copy and deleted comments are removed.
+/// Returns how many comments were added and how many were changed or removed.
+pub async fn store_external_comments(task_id: u64, comments: &[ExternalIssueComment]) -> Result<(u64, u64), String> {
+    let client = create_prisma_client().await?;
+    let stored: HashMap<String, DateTime<Utc>> = client
+        .external_comment()
+        .find_many(vec![external_comment::task_id::equals(task_id as i32)])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get external comments: {:?}", err);
+            err.to_string()
+        })?
+        .into_iter()
+        .map(|comment| (comment.external_id, comment.updated_at.with_timezone(&Utc)))
+        .collect();
+
+    let mut created = 0;
+    let mut updated = 0;
+    for comment in comments {
+        match stored.get(&comment.id) {
+            Some(updated_at) if *updated_at == comment.updated_at => continue,
+            Some(_) => updated += 1,
+            None => created += 1,
+        }
+        upsert_external_comment(task_id, comment).await?;
+    }
+
+    let removed = client
+        .external_comment()
+        .delete_many(vec![
+            external_comment::task_id::equals(task_id as i32),
+            external_comment::external_id::not_in_vec(comments.iter().map(|comment| comment.id.clone()).collect()),
+        ])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to remove external comments: {:?}", err);
+            err.to_string()
+        })?;
+
+    client
+        .task()
+        .update(
+            task::id::equals(task_id as i32),
+            vec![task::comments_synced_at::set(Some(Utc::now().into()))],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to update comment sync time: {:?}", err);
+            err.to_string()
+        })?;
+
+    Ok((created, updated + removed as u64))
+}
+
+/// Posts a reply to the issue linked to a task and adds it to the task's discussion.
+pub async fn post_discussion_reply(
+    providers: &IssueProviders,
+    token_key: &SecretKey,
+    user_id: u64,
+    task_id: u64,
+    body: &str,
+) -> Result<Option<SelectExternalComment>, String> {
+    let client = create_prisma_client().await?;
+    let task = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(task) => task,
+        None => return Ok(None),
+    };
+    require_project_participant(user_id, task.project_id as u64).await?;
+
+    let issue = task.assigned_issue
+        .ok_or_else(|| "Task is not linked to an issue".to_string())? as u64;
+    let link = get_repository_link(token_key, task.project_id as u64).await?
+        .ok_or_else(|| "Project has no linked repository".to_string())?;
+    let provider = providers.for_repository(&link).map_err(|err| err.to_string())?;
+
+    let author = match get_user(user_id).await? {
+        Some(user) => format!("{} {}", user.first_name, user.last_name),
+        None => "Krakker".to_string(),
+    };
+    let comment = provider
+        .comment(issue, &format!("**{}** (через Krakker):\n\n{}", author, body))
+        .await
+        .map_err(|err| format!("{:#}", err))?;
+
+    let stored = upsert_external_comment(task_id, &comment).await?;
+    Ok(Some(external_comment_to_response(&stored)))
+}
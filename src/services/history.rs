@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use prisma_client_rust::{Direction, QueryError};
+use serde_json::{json, Value};
+
+use crate::models::actor::Actor;
+use crate::models::history::{SelectTaskChange, SelectTaskHistoryPage, SelectTaskHistoryRequest, SelectTaskRevert};
+use crate::prisma::{label, task, task_change, user};
+use crate::services::board::next_board_position;
+use crate::services::common::{create_prisma_client, VersionedUpdateError};
+use crate::services::label::require_project_labels;
+use crate::services::task::{check_member_from_task, get_task_by_id, open_subtasks_to_complete, updated_by_params};
+use crate::services::user::{actor_to_response, is_project_member, is_project_owner};
+use crate::services::workflow::{check_transition, get_project_statuses};
+
+const LOG_TAG: &'static str = "HistoryService";
+const DEFAULT_PAGE_SIZE: u64 = 50;
+
+pub fn task_change_to_response(change: &task_change::Data) -> SelectTaskChange {
+    SelectTaskChange {
+        id: change.id as u64,
+        task_id: change.task_id as u64,
+        field: change.field.clone(),
+        old_value: change.old_value.clone(),
+        new_value: change.new_value.clone(),
+        changed_by: actor_to_response(
+            change.changed_by.as_ref().and_then(|user| user.as_deref()),
+            change.changed_by_system.as_deref(),
+        ),
+        changed_at: change.changed_at.timestamp() as u64,
+    }
+}
+
+fn sorted_ids(ids: impl Iterator<Item = i32>) -> Value {
+    let mut ids: Vec<i32> = ids.collect();
+    ids.sort_unstable();
+    json!(ids)
+}
+
+/// The tracked fields of a task. Assignees and labels are only included when they were fetched.
+fn task_fields(task: &task::Data) -> Vec<(&'static str, Option<Value>)> {
+    let mut fields = vec![
+        ("name", Some(json!(task.name))),
+        ("description", Some(json!(task.description))),
+        ("status", Some(json!(task.status))),
+        ("due_date", task.due_date.map(|date| json!(date.timestamp()))),
+        ("assigned_issue", task.assigned_issue.map(|issue| json!(issue))),
+        ("priority", Some(json!(task.priority))),
+        ("estimate", task.estimate.map(|estimate| json!(estimate))),
+        ("project", Some(json!(task.project_id))),
+    ];
+    if let Some(users) = &task.attached_to {
+        fields.push(("assignees", Some(sorted_ids(users.iter().map(|user| user.id)))));
+    }
+    if let Some(labels) = &task.labels {
+        fields.push(("labels", Some(sorted_ids(labels.iter().map(|label| label.id)))));
+    }
+    fields
+}
+
+/// Records the fields that differ between two states of a task as its history.
+/// Failures are logged rather than returned, since the change itself is already saved.
+pub async fn record_task_changes(actor: &Actor, before: &task::Data, after: &task::Data) {
+    let previous: HashMap<&str, Option<Value>> = task_fields(before).into_iter().collect();
+    let changes: Vec<(&str, Option<Value>, Option<Value>)> = task_fields(after)
+        .into_iter()
+        .filter_map(|(field, new_value)| {
+            let old_value = previous.get(field)?.clone();
+            (old_value != new_value).then_some((field, old_value, new_value))
+        })
+        .collect();
+    if changes.is_empty() {
+        return;
+    }
+
+    let client = match create_prisma_client().await {
+        Ok(client) => client,
+        Err(err) => {
+            log::error!(target: LOG_TAG, "Failed to create prisma client {err}");
+            return;
+        }
+    };
+    for (field, old_value, new_value) in changes {
+        let mut params = vec![
+            task_change::old_value::set(old_value),
+            task_change::new_value::set(new_value),
+        ];
+        match actor {
+            Actor::User(user_id) => params.push(task_change::changed_by::connect(user::id::equals(*user_id as i32))),
+            Actor::System(system) => params.push(task_change::changed_by_system::set(Some(system.to_string()))),
+        }
+        if let Err(err) = client
+            .task_change()
+            .create(task::id::equals(after.id), field.to_string(), params)
+            .exec()
+            .await
+        {
+            log::error!(target: LOG_TAG, "Failed to record change of {} in task {}: {:?}", field, after.id, err);
+        }
+    }
+}
+
+pub async fn get_task_history(
+    user_id: u64,
+    task_id: u64,
+    filters: &SelectTaskHistoryRequest,
+) -> Result<SelectTaskHistoryPage, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let page = filters.page.unwrap_or(1);
+    let per_page = filters.per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+
+    let client = create_prisma_client().await?;
+    let total = client
+        .task_change()
+        .count(vec![task_change::task_id::equals(task_id as i32)])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let changes = client
+        .task_change()
+        .find_many(vec![task_change::task_id::equals(task_id as i32)])
+        .with(task_change::changed_by::fetch())
+        .order_by(task_change::changed_at::order(Direction::Desc))
+        .order_by(task_change::id::order(Direction::Desc))
+        .skip(i64::try_from((page - 1).saturating_mul(per_page)).unwrap_or(i64::MAX))
+        .take(per_page as i64)
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get task history: {:?}", err);
+            err.to_string()
+        })?;
+
+    Ok(SelectTaskHistoryPage {
+        items: changes.iter().map(task_change_to_response).collect(),
+        page,
+        per_page,
+        total: total as u64,
+    })
+}
+
+fn string_value(value: &Option<Value>) -> Result<String, String> {
+    value.as_ref()
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+        .ok_or_else(|| "The previous value can't be restored".to_string())
+}
+
+fn ids_value(value: &Option<Value>) -> Result<Vec<u64>, String> {
+    value.as_ref()
+        .and_then(|value| value.as_array())
+        .and_then(|ids| ids.iter().map(|id| id.as_u64()).collect::<Option<Vec<u64>>>())
+        .ok_or_else(|| "The previous value can't be restored".to_string())
+}
+
+/// Restores the value a field had before the given change. The restore is itself recorded in the history.
+/// With `expected_version` it only applies to that version of the task.
+pub async fn revert_task_change(
+    user_id: u64,
+    task_id: u64,
+    change_id: u64,
+    expected_version: Option<u64>,
+) -> Result<Option<SelectTaskRevert>, VersionedUpdateError> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let change = match client
+        .task_change()
+        .find_unique(task_change::id::equals(change_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(change) if change.task_id as u64 == task_id => change,
+        _ => return Ok(None),
+    };
+    let before = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+    if expected_version.map_or(false, |version| version != before.version as u64) {
+        return Err(VersionedUpdateError::Changed);
+    }
+
+    let actor = Actor::User(user_id);
+    let mut skipped_assignee_ids = vec![];
+    let old_value = &change.old_value;
+    let mut update_properties = updated_by_params(&actor);
+    match change.field.as_str() {
+        "name" => update_properties.push(task::name::set(string_value(old_value)?)),
+        "description" => update_properties.push(task::description::set(string_value(old_value)?)),
+        "priority" => update_properties.push(task::priority::set(string_value(old_value)?)),
+        "status" => {
+            let status = string_value(old_value)?;
+            check_transition(before.project_id as u64, &before.status, &status).await?;
+            // A revert can't confirm completing open subtasks along with the task, so it is refused instead
+            let statuses = get_project_statuses(before.project_id as u64).await?;
+            if !open_subtasks_to_complete(&client, task_id, &statuses, &status, true).await?.is_empty() {
+                return Err("Task has open subtasks, complete them before reverting to this status".to_string().into());
+            }
+            let board_position = next_board_position(&client, before.project_id as u64, &status).await
+                .map_err(|err| err.to_string())?;
+            update_properties.push(task::board_position::set(board_position));
+            update_properties.push(task::status::set(status));
+            update_properties.push(task::status_before_block::set(None));
+        }
+        "due_date" => {
+            let due_date = old_value.as_ref().and_then(|value| value.as_i64())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0));
+            update_properties.push(task::due_date::set(due_date.map(|date| date.into())));
+        }
+        "assigned_issue" => {
+            let issue = old_value.as_ref().and_then(|value| value.as_i64());
+            update_properties.push(task::assigned_issue::set(issue.map(|issue| issue as i32)));
+        }
+        "estimate" => {
+            update_properties.push(task::estimate::set(old_value.as_ref().and_then(|value| value.as_f64())));
+        }
+        "labels" => {
+            let label_ids = ids_value(old_value)?;
+            // Labels deleted since then can't come back
+            let existing: Vec<u64> = client
+                .label()
+                .find_many(vec![label::id::in_vec(label_ids.iter().map(|id| *id as i32).collect())])
+                .exec()
+                .await
+                .map_err(|err| err.to_string())?
+                .iter()
+                .map(|label| label.id as u64)
+                .collect();
+            require_project_labels(before.project_id as u64, &existing).await?;
+            update_properties.push(task::labels::set(existing.iter().map(|id| label::id::equals(*id as i32)).collect()));
+        }
+        "assignees" => {
+            // Users who left the project since then are not assigned again
+            let mut user_ids = vec![];
+            for id in ids_value(old_value)? {
+                let project_id = before.project_id as u64;
+                if is_project_member(id, project_id).await? || is_project_owner(id, project_id).await? {
+                    user_ids.push(id);
+                } else {
+                    skipped_assignee_ids.push(id);
+                }
+            }
+            update_properties.push(task::attached_to::set(user_ids.iter().map(|id| user::id::equals(*id as i32)).collect()));
+        }
+        field => return Err(format!("Changes of {} can't be reverted", field).into()),
+    }
+
+    let after = client
+        ._transaction()
+        .run(|tx| async move {
+            if let Some(version) = expected_version {
+                let claimed = tx
+                    .task()
+                    .update_many(
+                        vec![task::id::equals(task_id as i32), task::version::equals(version as i32)],
+                        vec![task::version::set(version as i32)],
+                    )
+                    .exec()
+                    .await?;
+                if claimed == 0 {
+                    return Ok(None);
+                }
+            }
+            tx.task()
+                .update(task::id::equals(task_id as i32), update_properties)
+                .with(task::attached_to::fetch(vec![]))
+                .with(task::labels::fetch(vec![]))
+                .exec()
+                .await
+                .map(Some)
+        })
+        .await
+        .map_err(|err: QueryError| {
+            log::error!(target: LOG_TAG, "Failed to revert change {change_id} of task {task_id}: {:?}", err);
+            err.to_string()
+        })?
+        .ok_or(VersionedUpdateError::Changed)?;
+    record_task_changes(&actor, &before, &after).await;
+
+    Ok(get_task_by_id(task_id).await?.map(|task| SelectTaskRevert { task, skipped_assignee_ids }))
+}
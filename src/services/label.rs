@@ -0,0 +1,146 @@
+use prisma_client_rust::{Direction, QueryError};
+use prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation;
+
+use crate::models::label::{CreateLabelRequest, SelectLabel, UpdateLabelRequest};
+use crate::prisma::{label, project};
+use crate::services::common::create_prisma_client;
+use crate::services::task::require_project_participant;
+
+const LOG_TAG: &'static str = "LabelService";
+
+pub fn label_to_response(label: &label::Data) -> SelectLabel {
+    SelectLabel {
+        id: label.id as u64,
+        project_id: label.project_id as u64,
+        name: label.name.clone(),
+        color: label.color.to_lowercase(),
+    }
+}
+
+fn query_error_to_string(err: QueryError) -> String {
+    if err.is_prisma_error::<UniqueKeyViolation>() {
+        return "The project already has a label with this name".to_string();
+    }
+    log::error!(target: LOG_TAG, "Failed to save label: {:?}", err);
+    err.to_string()
+}
+
+pub async fn get_project_labels(user_id: u64, project_id: u64) -> Result<Vec<SelectLabel>, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let labels = client
+        .label()
+        .find_many(vec![label::project_id::equals(project_id as i32)])
+        .order_by(label::name::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get labels: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(labels.iter().map(label_to_response).collect())
+}
+
+/// Checks that all labels belong to the project, so tasks only get labels of their own project.
+pub async fn require_project_labels(project_id: u64, label_ids: &[u64]) -> Result<(), String> {
+    if label_ids.is_empty() {
+        return Ok(());
+    }
+    let client = create_prisma_client().await?;
+    let found = client
+        .label()
+        .count(vec![
+            label::project_id::equals(project_id as i32),
+            label::id::in_vec(label_ids.iter().map(|id| *id as i32).collect()),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut unique_ids = label_ids.to_vec();
+    unique_ids.sort_unstable();
+    unique_ids.dedup();
+    if found as usize != unique_ids.len() {
+        return Err("Some labels don't belong to the task's project".to_string());
+    }
+    Ok(())
+}
+
+pub async fn create_label(user_id: u64, project_id: u64, request: &CreateLabelRequest) -> Result<SelectLabel, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let label = client
+        .label()
+        .create(
+            project::id::equals(project_id as i32),
+            request.name.clone(),
+            request.color.to_lowercase(),
+            vec![],
+        )
+        .exec()
+        .await
+        .map_err(query_error_to_string)?;
+    Ok(label_to_response(&label))
+}
+
+pub async fn update_label(
+    user_id: u64,
+    project_id: u64,
+    label_id: u64,
+    request: &UpdateLabelRequest,
+) -> Result<Option<SelectLabel>, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let mut update_properties = vec![];
+    if let Some(name) = request.name.clone() {
+        update_properties.push(label::name::set(name));
+    }
+    if let Some(color) = &request.color {
+        update_properties.push(label::color::set(color.to_lowercase()));
+    }
+    let updated = client
+        .label()
+        .update_many(
+            vec![
+                label::id::equals(label_id as i32),
+                label::project_id::equals(project_id as i32),
+            ],
+            update_properties,
+        )
+        .exec()
+        .await
+        .map_err(query_error_to_string)?;
+    if updated == 0 {
+        return Ok(None);
+    }
+
+    let label = client
+        .label()
+        .find_unique(label::id::equals(label_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(label.as_ref().map(label_to_response))
+}
+
+/// Deletes a label, removing it from every task it was put on.
+pub async fn delete_label(user_id: u64, project_id: u64, label_id: u64) -> Result<Option<()>, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let deleted = client
+        .label()
+        .delete_many(vec![
+            label::id::equals(label_id as i32),
+            label::project_id::equals(project_id as i32),
+        ])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to delete label: {:?}", err);
+            err.to_string()
+        })?;
+    Ok((deleted > 0).then_some(()))
+}
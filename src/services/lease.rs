@@ -0,0 +1,95 @@
+use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use prisma_client_rust::{raw, PrismaValue};
+
+use crate::prisma::worker_lease;
+use crate::services::common::create_prisma_client;
+
+const LOG_TAG: &'static str = "LeaseService";
+
+/// Identifies this replica as a lease holder.
+pub fn instance_id() -> &'static str {
+    static INSTANCE_ID: OnceLock<String> = OnceLock::new();
+    INSTANCE_ID.get_or_init(|| {
+        let host = env::var("HOSTNAME").unwrap_or_else(|_| "krakker".to_string());
+        let started = chrono::Utc::now().timestamp_millis();
+        format!("{}-{}-{}", host, std::process::id(), started)
+    })
+}
+
+/// Takes or renews the named lease. Returns `true` when this instance holds it afterwards,
+/// so only one replica runs the worker guarded by the lease.
+pub async fn try_acquire_lease(name: &str, ttl: Duration) -> Result<bool, String> {
+    let client = create_prisma_client().await?;
+    let acquired = client
+        ._execute_raw(raw!(
+            r#"INSERT INTO "WorkerLease" ("name", "holder", "expiresAt")
+            VALUES ({}, {}, CURRENT_TIMESTAMP + (CAST({} AS DOUBLE PRECISION) * INTERVAL '1 millisecond'))
+            ON CONFLICT ("name") DO UPDATE
+            SET "holder" = EXCLUDED."holder", "expiresAt" = EXCLUDED."expiresAt"
+            WHERE "WorkerLease"."holder" = EXCLUDED."holder" OR "WorkerLease"."expiresAt" < CURRENT_TIMESTAMP"#,
+            PrismaValue::String(name.to_string()),
+            PrismaValue::String(instance_id().to_string()),
+            PrismaValue::Int(ttl.as_millis() as i64)
+        ))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to acquire lease {name}: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(acquired > 0)
+}
+
+/// Takes or renews the lease of a worker that runs every `interval`. `is_leader` tracks
+/// whether this instance held it last time, so a handover is logged once.
+pub async fn hold_lease(name: &str, interval: Duration, is_leader: &AtomicBool) -> bool {
+    // The lease outlives a few missed renewals, so a slow cycle does not hand it over
+    let held = match try_acquire_lease(name, interval * 3).await {
+        Ok(held) => held,
+        Err(e) => {
+            log::error!(target: LOG_TAG, "Failed to acquire lease {name}: {}", e);
+            false
+        }
+    };
+    if held != is_leader.swap(held, Ordering::Relaxed) {
+        if held {
+            log::info!(target: LOG_TAG, "This instance now holds lease {name}");
+        } else {
+            log::info!(target: LOG_TAG, "Lease {name} is held by another instance");
+        }
+    }
+    held
+}
+
+/// Renews a held lease every `interval` and returns once it is lost. Racing a long job against it
+/// stops the job before the lease expires and another instance takes over.
+pub async fn keep_lease(name: &str, interval: Duration, is_leader: &AtomicBool) {
+    let mut renewal = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+    loop {
+        renewal.tick().await;
+        if !hold_lease(name, interval, is_leader).await {
+            return;
+        }
+    }
+}
+
+pub async fn release_lease(name: &str) -> Result<(), String> {
+    let client = create_prisma_client().await?;
+    client
+        .worker_lease()
+        .delete_many(vec![
+            worker_lease::name::equals(name.to_string()),
+            worker_lease::holder::equals(instance_id().to_string()),
+        ])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to release lease {name}: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(())
+}
@@ -3,3 +3,21 @@ pub mod project;
 pub mod common;
 pub mod task;
 pub mod notifications;
+pub mod sync;
+pub mod lease;
+pub mod discussion;
+pub mod comment;
+pub mod attachment;
+pub mod avatar;
+pub mod dependency;
+pub mod workflow;
+pub mod board;
+pub mod label;
+pub mod time_entry;
+pub mod history;
+pub mod search;
+pub mod bulk;
+pub mod recurrence;
+pub mod template;
+pub mod transfer;
+pub mod watcher;
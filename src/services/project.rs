@@ -1,14 +1,71 @@
-use prisma_client_rust::or;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use prisma_client_rust::{or, QueryError};
+use reqwest::Url;
+
+use crate::config::RepositorySettings;
 use crate::models::{
-    project::{CreateProjectRequest, SelectProject, UpdateProjectRequest},
+    project::{CreateProjectRequest, RepositoryLink, RepositoryProvider, SelectProject, UpdateProjectRequest},
     user::SelectUser,
 };
 use crate::prisma::{project, task, user};
-use crate::services::common::create_prisma_client;
+use crate::services::common::{create_prisma_client, VersionedUpdateError};
 use crate::services::task::task_data_to_response;
 use crate::services::user::{get_user, is_project_member, user_data_to_response};
+use crate::services::workflow::create_default_workflow;
+use crate::utils::secret::{decrypt_secret, encrypt_secret, SecretKey};
 
 const LOG_TAG: &'static str = "ProjectService";
+pub const PROJECT_CHANGED: &'static str = "Project was changed since the given version";
+
+pub fn repository_provider_from_str(provider: &str) -> RepositoryProvider {
+    RepositoryProvider::from_str(provider).unwrap_or(RepositoryProvider::GitHub)
+}
+
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let shared = ip.octets()[0] == 100 && ip.octets()[1] & 0xc0 == 64;
+            !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified()
+                || ip.is_broadcast() || ip.is_documentation() || shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_address(IpAddr::V4(ip)),
+            None => {
+                let unique_local = ip.segments()[0] & 0xfe00 == 0xfc00;
+                let link_local = ip.segments()[0] & 0xffc0 == 0xfe80;
+                !(ip.is_loopback() || ip.is_unspecified() || unique_local || link_local)
+            }
+        },
+    }
+}
+
+/// Checks that a repository base url is an https url of a public host, so the sync can't be
+/// pointed at services of the internal network.
+async fn check_repository_base_url(settings: &RepositorySettings, base_url: &str) -> Result<(), String> {
+    let url = Url::parse(base_url).map_err(|_| "Invalid repository base url".to_string())?;
+    if url.scheme() != "https" {
+        return Err("Repository base url must use https".to_string());
+    }
+    let host = url.host_str().ok_or_else(|| "Repository base url has no host".to_string())?;
+    if settings.allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) {
+        return Ok(());
+    }
+
+    let addresses: Vec<IpAddr> = match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(ip) => vec![ip],
+        Err(_) => tokio::net::lookup_host((host, url.port_or_known_default().unwrap_or(443)))
+            .await
+            .map_err(|_| format!("Repository host {} can't be resolved", host))?
+            .map(|address| address.ip())
+            .collect(),
+    };
+    if addresses.is_empty() || !addresses.into_iter().all(is_public_address) {
+        return Err(format!("Repository host {} is not public", host));
+    }
+    Ok(())
+}
 
 pub async fn project_data_to_response(project: &project::Data) -> Result<SelectProject, String> {
     Ok(SelectProject {
@@ -41,6 +98,9 @@ pub async fn project_data_to_response(project: &project::Data) -> Result<SelectP
             Err(err) => return Err(err.to_string()),
         },
         repository_id: project.repo_id.clone(),
+        repository_provider: repository_provider_from_str(&project.repo_provider),
+        repository_base_url: project.repo_base_url.clone(),
+        version: project.version as u64,
     })
 }
 
@@ -49,7 +109,16 @@ pub async fn get_all_projects() -> Result<Vec<SelectProject>, String> {
     let projects = client.project()
         .find_many(vec![])
         .with(project::owner::fetch())
-        .with(project::tasks::fetch(vec![]).with(task::attached_to::fetch(vec![])))
+        .with(
+            project::tasks::fetch(vec![])
+                .with(task::attached_to::fetch(vec![]))
+                .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+                .with(task::created_by::fetch())
+                .with(task::updated_by::fetch())
+                .with(task::children::fetch(vec![]))
+                .with(task::labels::fetch(vec![]))
+                .with(task::time_entries::fetch(vec![])),
+        )
         .with(project::members::fetch(vec![]))
         .exec().await;
     match projects {
@@ -67,6 +136,28 @@ pub async fn get_all_projects() -> Result<Vec<SelectProject>, String> {
     }
 }
 
+pub async fn get_repository_link(token_key: &SecretKey, project_id: u64) -> Result<Option<RepositoryLink>, String> {
+    let client = create_prisma_client().await?;
+    let project = client
+        .project()
+        .find_unique(project::id::equals(project_id as i32))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project repository: {:?}", err);
+            err.to_string()
+        })?;
+    let Some((project, repository_id)) = project.and_then(|project| project.repo_id.clone().map(|id| (project, id))) else {
+        return Ok(None);
+    };
+    Ok(Some(RepositoryLink {
+        provider: repository_provider_from_str(&project.repo_provider),
+        repository_id,
+        base_url: project.repo_base_url.clone(),
+        token: project.repo_token.as_deref().map(|token| decrypt_secret(token_key, token)).transpose()?,
+    }))
+}
+
 pub async fn get_user_projects(user_id: u64) -> Result<Vec<SelectProject>, String> {
     let client = create_prisma_client().await?;
     let projects = client
@@ -76,7 +167,16 @@ pub async fn get_user_projects(user_id: u64) -> Result<Vec<SelectProject>, Strin
             project::members::some(vec![user::id::equals(user_id as i32)])
         )])
         .with(project::owner::fetch())
-        .with(project::tasks::fetch(vec![]).with(task::attached_to::fetch(vec![])))
+        .with(
+            project::tasks::fetch(vec![])
+                .with(task::attached_to::fetch(vec![]))
+                .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+                .with(task::created_by::fetch())
+                .with(task::updated_by::fetch())
+                .with(task::children::fetch(vec![]))
+                .with(task::labels::fetch(vec![]))
+                .with(task::time_entries::fetch(vec![])),
+        )
         .with(project::members::fetch(vec![]))
         .exec()
         .await;
@@ -110,7 +210,16 @@ pub async fn get_project_by_id(
             ),
         ])
         .with(project::owner::fetch())
-        .with(project::tasks::fetch(vec![]).with(task::attached_to::fetch(vec![])))
+        .with(
+            project::tasks::fetch(vec![])
+                .with(task::attached_to::fetch(vec![]))
+                .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+                .with(task::created_by::fetch())
+                .with(task::updated_by::fetch())
+                .with(task::children::fetch(vec![]))
+                .with(task::labels::fetch(vec![]))
+                .with(task::time_entries::fetch(vec![])),
+        )
         .with(project::members::fetch(vec![]))
         .exec()
         .await;
@@ -129,20 +238,24 @@ pub async fn create_project(
     data: &CreateProjectRequest,
 ) -> Result<SelectProject, String> {
     let client = create_prisma_client().await?;
-    let project = client
-        .project()
-        .create(
-            data.name.clone(),
-            data.description.clone(),
-            user::id::equals(owner_id as i32),
-            vec![project::members::connect(
-                data.members
-                    .iter()
-                    .map(|id| user::id::equals(*id as i32))
-                    .collect(),
-            )],
-        )
-        .exec()
+    let members: Vec<_> = data.members.iter().map(|id| user::id::equals(*id as i32)).collect();
+    // A project without statuses can't hold tasks, so it is only created together with its workflow
+    let project: Result<project::Data, QueryError> = client
+        ._transaction()
+        .run(|tx| async move {
+            let project = tx
+                .project()
+                .create(
+                    data.name.clone(),
+                    data.description.clone(),
+                    user::id::equals(owner_id as i32),
+                    vec![project::members::connect(members)],
+                )
+                .exec()
+                .await?;
+            create_default_workflow(&tx, project.id as u64).await?;
+            Ok(project)
+        })
         .await;
 
     let owner = get_user(owner_id).await.map_err(|err| {
@@ -160,7 +273,10 @@ pub async fn create_project(
                 owner,
                 members: vec![],
                 tasks: vec![],
-                repository_id: project.repo_id.clone()
+                repository_id: project.repo_id.clone(),
+                repository_provider: repository_provider_from_str(&project.repo_provider),
+                repository_base_url: project.repo_base_url.clone(),
+                version: project.version as u64,
             })
             .map_err(|err| {
                 log::error!(target: LOG_TAG, "Failed to create project: {:?}", err);
@@ -172,31 +288,38 @@ pub async fn create_project(
     }
 }
 
+/// Updates a project. With `expected_version` the update only applies to that version of the project.
 pub async fn update_project(
+    settings: &RepositorySettings,
     owner_id: u64,
     project_id: u64,
     data: &UpdateProjectRequest,
-) -> Result<Option<SelectProject>, String> {
+    expected_version: Option<u64>,
+) -> Result<Option<SelectProject>, VersionedUpdateError> {
     let client = create_prisma_client().await?;
     let project = get_project_by_id(owner_id, project_id).await;
-    match project {
+    let existing = match project {
         Ok(Some(project)) => {
             if project.owner.id != owner_id {
                 log::error!(target: LOG_TAG, "User {owner_id} is not the owner of the project {project_id}");
-                return Err("User is not the owner of the project".to_string());
+                return Err("User is not the owner of the project".to_string().into());
+            }
+            if expected_version.map_or(false, |version| version != project.version) {
+                return Err(VersionedUpdateError::Changed);
             }
+            project
         }
         Ok(None) => {
             log::error!(target: LOG_TAG, "Project not found");
-            return Err("Project not found".to_string());
+            return Err("Project not found".to_string().into());
         }
         Err(err) => {
             log::error!(target: LOG_TAG, "Failed to get project by id: {:?}", err);
-            return Err(err.to_string());
+            return Err(err.to_string().into());
         }
     };
 
-    let mut updates: Vec<_> = vec![];
+    let mut updates: Vec<_> = vec![project::version::increment(1)];
 
     if data.name.is_some() {
         updates.push(project::name::set(data.name.clone().unwrap()));
@@ -208,30 +331,75 @@ pub async fn update_project(
         updates.push(project::owner_id::set(data.owner_id.unwrap() as i32));
     }
     if let Some(repo_id) = data.clone().repository_id {
+        let provider = data.repository_provider.unwrap_or(RepositoryProvider::GitHub);
+        if provider == RepositoryProvider::Gitea && data.repository_base_url.is_none() {
+            return Err("Gitea repositories require a base url".to_string().into());
+        }
+        if let Some(base_url) = &data.repository_base_url {
+            check_repository_base_url(settings, base_url).await?;
+        }
         updates.push(project::repo_id::set(Some(repo_id)));
+        updates.push(project::repo_provider::set(provider.to_string()));
+        updates.push(project::repo_base_url::set(data.repository_base_url.clone()));
+        // A token is only ever sent to the host it was given for
+        let host_changed = provider != existing.repository_provider
+            || data.repository_base_url != existing.repository_base_url;
+        match &data.repository_token {
+            Some(token) => updates.push(project::repo_token::set(Some(encrypt_secret(&settings.token_key, token)?))),
+            None if host_changed => updates.push(project::repo_token::set(None)),
+            None => {}
+        }
     } else {
         updates.push(project::repo_id::set(None));
+        updates.push(project::repo_provider::set(RepositoryProvider::GitHub.to_string()));
+        updates.push(project::repo_base_url::set(None));
+        updates.push(project::repo_token::set(None));
     }
 
-    let new_project = client
-        .project()
-        .update(project::id::equals(project_id as i32), updates)
-        .with(project::owner::fetch())
-        .with(project::tasks::fetch(vec![]).with(task::attached_to::fetch(vec![])))
-        .with(project::members::fetch(vec![]))
-        .exec()
+    let new_project: Result<Option<project::Data>, QueryError> = client
+        ._transaction()
+        .run(|tx| async move {
+            // Locks the project until the update commits, so a concurrent update based on the same version misses it
+            if let Some(version) = expected_version {
+                let claimed = tx
+                    .project()
+                    .update_many(
+                        vec![project::id::equals(project_id as i32), project::version::equals(version as i32)],
+                        vec![project::version::set(version as i32)],
+                    )
+                    .exec()
+                    .await?;
+                if claimed == 0 {
+                    return Ok(None);
+                }
+            }
+            tx.project()
+                .update(project::id::equals(project_id as i32), updates)
+                .with(project::owner::fetch())
+                .with(
+                    project::tasks::fetch(vec![])
+                        .with(task::attached_to::fetch(vec![]))
+                        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+                        .with(task::created_by::fetch())
+                        .with(task::updated_by::fetch())
+                        .with(task::children::fetch(vec![]))
+                        .with(task::labels::fetch(vec![]))
+                        .with(task::time_entries::fetch(vec![])),
+                )
+                .with(project::members::fetch(vec![]))
+                .exec()
+                .await
+                .map(Some)
+        })
         .await;
     match new_project {
-        Ok(project) => Ok(Some(project_data_to_response(&project).await?)),
+        Ok(Some(project)) => Ok(Some(project_data_to_response(&project).await?)),
+        Ok(None) => Err(VersionedUpdateError::Changed),
         Err(e) => {
             log::error!(target: LOG_TAG, "Failed to update project: {:?}", e);
-            Err(e.to_string())
+            Err(e.to_string().into())
         }
     }
-    .map_err(|err| {
-        log::error!(target: LOG_TAG, "Failed to update project: {:?}", err);
-        err.to_string()
-    })
 }
 
 pub async fn delete_project(owner_id: u64, project_id: u64) -> Result<(), String> {
@@ -298,9 +466,10 @@ pub async fn add_project_member(
                         .project()
                         .update(
                             project::id::equals(project_id as i32),
-                            vec![project::members::connect(vec![user::id::equals(
-                                user_id as i32,
-                            )])],
+                            vec![
+                                project::members::connect(vec![user::id::equals(user_id as i32)]),
+                                project::version::increment(1),
+                            ],
                         )
                         .exec()
                         .await;
@@ -361,9 +530,10 @@ pub async fn remove_project_member(
                         .project()
                         .update(
                             project::id::equals(project_id as i32),
-                            vec![project::members::disconnect(vec![user::id::equals(
-                                user_id as i32,
-                            )])],
+                            vec![
+                                project::members::disconnect(vec![user::id::equals(user_id as i32)]),
+                                project::version::increment(1),
+                            ],
                         )
                         .exec()
                         .await;
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use prisma_client_rust::QueryError;
+
+use crate::mailer::mailer::Mailer;
+use crate::models::actor::{Actor, SystemActor};
+use crate::models::recurrence::{RecurrenceFrequency, RecurrenceRule, SelectRecurrence, SetRecurrenceRequest};
+use crate::models::task::{CreateTaskRequest, TaskPriority};
+use crate::prisma::{project, project_status, task, task_recurrence, PrismaClient};
+use crate::services::common::create_prisma_client;
+use crate::services::notifications::create_notification;
+use crate::services::task::{check_member_from_task, create_task};
+use crate::services::workflow::{get_project_statuses, status_category};
+
+const LOG_TAG: &'static str = "RecurrenceService";
+const ACTOR: Actor = Actor::System(SystemActor::Recurrence);
+/// Bounds the catch-up over occurrences missed while no instance could be created.
+const MAX_SKIPPED_OCCURRENCES: usize = 10_000;
+
+/// The first occurrence of a rule after the given time, keeping its time of day.
+/// Weekdays and days of month are taken in UTC.
+pub fn next_occurrence(rule: &RecurrenceRule, after: DateTime<Utc>) -> DateTime<Utc> {
+    let interval = rule.interval.max(1) as i64;
+    match rule.frequency {
+        RecurrenceFrequency::Daily => after + Duration::days(interval),
+        RecurrenceFrequency::Weekly if rule.weekdays.is_empty() => after + Duration::weeks(interval),
+        RecurrenceFrequency::Weekly => {
+            let week_start = |date: DateTime<Utc>| date.date_naive() - Duration::days(date.weekday().num_days_from_monday() as i64);
+            let anchor = week_start(after);
+            // Within the rest of this week and the next active week a listed weekday always comes up
+            (1..=7 * (interval + 1))
+                .map(|days| after + Duration::days(days))
+                .find(|candidate| {
+                    let weeks = (week_start(*candidate) - anchor).num_days() / 7;
+                    weeks % interval == 0 && rule.weekdays.contains(&candidate.weekday())
+                })
+                .unwrap_or(after + Duration::weeks(interval))
+        }
+        RecurrenceFrequency::Monthly => {
+            let day = rule.month_day.unwrap_or(after.day());
+            [0, interval]
+                .iter()
+                .filter_map(|months| {
+                    let total = after.year() as i64 * 12 + after.month0() as i64 + months;
+                    let (year, month) = ((total / 12) as i32, (total % 12) as u32 + 1);
+                    // Short months take their last day instead
+                    let date = (1..=day).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day))?;
+                    Some(date.and_time(after.time()).and_utc())
+                })
+                .find(|candidate| *candidate > after)
+                .unwrap_or(after + Duration::days(30 * interval))
+        }
+    }
+}
+
+/// Due date of the instance following one due at `due_date`, skipping occurrences already past.
+fn next_due_date(rule: &RecurrenceRule, due_date: Option<DateTime<Utc>>) -> DateTime<Utc> {
+    let now = Utc::now();
+    let mut next = next_occurrence(rule, due_date.unwrap_or(now));
+    for _ in 0..MAX_SKIPPED_OCCURRENCES {
+        if next > now {
+            break;
+        }
+        next = next_occurrence(rule, next);
+    }
+    next
+}
+
+fn recurrence_to_response(recurrence: &task_recurrence::Data, due_date: Option<DateTime<Utc>>) -> SelectRecurrence {
+    let until = recurrence.until.map(|until| until.with_timezone(&Utc));
+    let next = RecurrenceRule::from_str(&recurrence.rule).ok()
+        .map(|rule| next_due_date(&rule, due_date))
+        .filter(|next| recurrence.active && until.map_or(true, |until| *next <= until));
+    SelectRecurrence {
+        id: recurrence.id as u64,
+        rule: recurrence.rule.clone(),
+        until: until.map(|until| until.timestamp() as u64),
+        active: recurrence.active,
+        next_occurrence: next.map(|next| next.timestamp() as u64),
+    }
+}
+
+pub async fn get_task_recurrence(user_id: u64, task_id: u64) -> Result<Option<SelectRecurrence>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let task = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::recurrence::fetch())
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(task.and_then(|task| {
+        let due_date = task.due_date.map(|date| date.with_timezone(&Utc));
+        task.recurrence.flatten().map(|recurrence| recurrence_to_response(&recurrence, due_date))
+    }))
+}
+
+/// Makes a task recurring, or changes the rule of its series. The change applies to the instances
+/// created from now on and resumes a stopped series.
+pub async fn set_task_recurrence(user_id: u64, task_id: u64, request: &SetRecurrenceRequest) -> Result<SelectRecurrence, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let rule = RecurrenceRule::from_str(&request.rule)?;
+    let until = match request.until {
+        Some(until) => Some(DateTime::from_timestamp(until as i64, 0).ok_or_else(|| "Invalid end of the series".to_string())?),
+        None => None,
+    };
+
+    let client = create_prisma_client().await?;
+    let task = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+
+    let recurrence = match task.recurrence_id {
+        Some(recurrence_id) => client
+            .task_recurrence()
+            .update(
+                task_recurrence::id::equals(recurrence_id),
+                vec![
+                    task_recurrence::rule::set(rule.to_string()),
+                    task_recurrence::until::set(until.map(|until| until.into())),
+                    task_recurrence::active::set(true),
+                ],
+            )
+            .exec()
+            .await,
+        // The series only exists together with its first task
+        None => client
+            ._transaction()
+            .run(|tx| async move {
+                let recurrence = tx
+                    .task_recurrence()
+                    .create(
+                        project::id::equals(task.project_id),
+                        rule.to_string(),
+                        vec![task_recurrence::until::set(until.map(|until| until.into()))],
+                    )
+                    .exec()
+                    .await?;
+                tx.task()
+                    .update(
+                        task::id::equals(task.id),
+                        vec![
+                            task::recurrence::connect(task_recurrence::id::equals(recurrence.id)),
+                            task::next_created::set(false),
+                        ],
+                    )
+                    .exec()
+                    .await?;
+                Ok(recurrence)
+            })
+            .await,
+    }
+    .map_err(|err: QueryError| {
+        log::error!(target: LOG_TAG, "Failed to set recurrence of task {task_id}: {:?}", err);
+        err.to_string()
+    })?;
+
+    Ok(recurrence_to_response(&recurrence, task.due_date.map(|date| date.with_timezone(&Utc))))
+}
+
+/// Stops a series. Existing instances stay, no new ones are created.
+pub async fn stop_task_recurrence(user_id: u64, task_id: u64) -> Result<Option<SelectRecurrence>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let task = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(task) => task,
+        None => return Ok(None),
+    };
+    let Some(recurrence_id) = task.recurrence_id else {
+        return Ok(None);
+    };
+
+    let recurrence = client
+        .task_recurrence()
+        .update(task_recurrence::id::equals(recurrence_id), vec![task_recurrence::active::set(false)])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to stop recurrence {recurrence_id}: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(Some(recurrence_to_response(&recurrence, task.due_date.map(|date| date.with_timezone(&Utc)))))
+}
+
+/// Creates the next instance of every active series whose current instance is finished or due.
+/// Returns the number of created instances.
+pub async fn create_due_instances(mailer: &Mailer) -> Result<u64, String> {
+    let client = create_prisma_client().await?;
+    let current = client
+        .task()
+        .find_many(vec![
+            task::next_created::equals(false),
+            task::recurrence::is(vec![task_recurrence::active::equals(true)]),
+        ])
+        .with(task::recurrence::fetch())
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let now = Utc::now();
+    let mut statuses: HashMap<i32, Vec<project_status::Data>> = HashMap::new();
+    let mut created = 0;
+    for instance in current {
+        if !statuses.contains_key(&instance.project_id) {
+            statuses.insert(instance.project_id, get_project_statuses(instance.project_id as u64).await?);
+        }
+        let finished = status_category(&statuses[&instance.project_id], &instance.status).is_finished();
+        let due_date = instance.due_date.map(|date| date.with_timezone(&Utc));
+        if !finished && due_date.map_or(true, |due_date| due_date > now) {
+            continue;
+        }
+
+        match create_next_instance(&instance, due_date, mailer).await {
+            Ok(true) => created += 1,
+            Ok(false) => {}
+            Err(err) => log::error!(target: LOG_TAG, "Failed to create next instance of task {}: {}", instance.id, err),
+        }
+    }
+    Ok(created)
+}
+
+/// Releases the claim on an instance, so the next pass tries to continue its series again.
+async fn release_instance(client: &PrismaClient, instance_id: i32) {
+    if let Err(err) = client
+        .task()
+        .update(task::id::equals(instance_id), vec![task::next_created::set(false)])
+        .exec()
+        .await
+    {
+        log::error!(target: LOG_TAG, "Failed to release task {instance_id}: {:?}", err);
+    }
+}
+
+async fn create_next_instance(instance: &task::Data, due_date: Option<DateTime<Utc>>, mailer: &Mailer) -> Result<bool, String> {
+    let Some(recurrence) = instance.recurrence.clone().flatten() else {
+        return Ok(false);
+    };
+    let client = create_prisma_client().await?;
+
+    // Claiming the instance first keeps a slow pass from creating the next one twice
+    let claimed = client
+        .task()
+        .update_many(
+            vec![task::id::equals(instance.id), task::next_created::equals(false)],
+            vec![task::next_created::set(true)],
+        )
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    if claimed == 0 {
+        return Ok(false);
+    }
+
+    let rule = RecurrenceRule::from_str(&recurrence.rule)?;
+    let next = next_due_date(&rule, due_date);
+    if recurrence.until.map_or(false, |until| next > until.with_timezone(&Utc)) {
+        if let Err(err) = client
+            .task_recurrence()
+            .update(task_recurrence::id::equals(recurrence.id), vec![task_recurrence::active::set(false)])
+            .exec()
+            .await
+        {
+            release_instance(&client, instance.id).await;
+            return Err(err.to_string());
+        }
+        log::info!(target: LOG_TAG, "Series {} is over", recurrence.id);
+        return Ok(false);
+    }
+
+    let attached_to: Vec<u64> = instance.attached_to.iter().flatten().map(|user| user.id as u64).collect();
+    let request = CreateTaskRequest {
+        name: instance.name.clone(),
+        description: instance.description.clone(),
+        project_id: instance.project_id as u64,
+        attached_to: attached_to.clone(),
+        due_date: Some(next.timestamp() as u64),
+        assigned_issue: None,
+        parent_id: instance.parent_id.map(|id| id as u64),
+        priority: TaskPriority::from_str(&instance.priority).ok(),
+        estimate: instance.estimate,
+        label_ids: instance.labels.iter().flatten().map(|label| label.id as u64).collect(),
+    };
+    let created = match create_task(ACTOR, &request).await {
+        Ok(created) => created,
+        Err(err) => {
+            release_instance(&client, instance.id).await;
+            return Err(err);
+        }
+    };
+    if let Err(err) = client
+        .task()
+        .update(
+            task::id::equals(created.id as i32),
+            vec![task::recurrence::connect(task_recurrence::id::equals(recurrence.id))],
+        )
+        .exec()
+        .await
+    {
+        // A task outside the series would never continue it, so it is dropped and created again
+        client.task().delete(task::id::equals(created.id as i32)).exec().await.ok();
+        release_instance(&client, instance.id).await;
+        return Err(err.to_string());
+    }
+
+    for user_id in attached_to {
+        create_notification(
+            "Новая повторяющаяся задача".to_string(),
+            format!("Создана очередная задача {} со сроком {}.", created.name, next.format("%d.%m.%Y")),
+            user_id,
+            mailer,
+        ).await;
+    }
+    Ok(true)
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use prisma_client_rust::{raw, PrismaValue};
+use serde::Deserialize;
+
+use crate::models::search::{SearchQuery, SearchResultKind, SelectSearchResult, SelectSearchResults};
+use crate::services::common::create_prisma_client;
+
+const LOG_TAG: &'static str = "SearchService";
+const DEFAULT_PAGE_SIZE: u64 = 20;
+/// Control characters mark the matches in `ts_headline` output, so the text around them
+/// can be escaped before the marks become `<mark>` tags.
+const MATCH_START: &'static str = "\u{2}";
+const MATCH_END: &'static str = "\u{3}";
+
+#[derive(Debug, Deserialize)]
+struct SearchRow {
+    kind: String,
+    id: i64,
+    project_id: i64,
+    task_id: Option<i64>,
+    title: String,
+    snippet: String,
+    rank: f64,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn highlight(snippet: &str) -> String {
+    escape_html(snippet)
+        .replace(MATCH_START, "<mark>")
+        .replace(MATCH_END, "</mark>")
+}
+
+/// Searches the names and descriptions of projects and tasks and the comments of the projects
+/// the user owns or is a member of. Results are ordered by relevance.
+pub async fn full_text_search(user_id: u64, query: &SearchQuery) -> Result<SelectSearchResults, String> {
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+    let project_id = query.project_id.map_or(PrismaValue::Null, |id| PrismaValue::Int(id as i64));
+    let kind = query.kind.map_or(PrismaValue::Null, |kind| PrismaValue::String(kind.to_string()));
+    let headline_options = format!("StartSel=\"{MATCH_START}\", StopSel=\"{MATCH_END}\", MaxFragments=2, MaxWords=30, MinWords=10");
+
+    let client = create_prisma_client().await?;
+    let rows: Vec<SearchRow> = client
+        ._query_raw(raw!(
+            r#"WITH "query" AS (
+                SELECT websearch_to_tsquery('russian', {}) AS "q", CAST({} AS TEXT) AS "options"
+            ), "projects" AS (
+                SELECT "Project"."id" FROM "Project"
+                WHERE ("Project"."ownerId" = {}
+                    OR EXISTS (SELECT 1 FROM "_ProjectMembers" WHERE "_ProjectMembers"."A" = "Project"."id" AND "_ProjectMembers"."B" = {}))
+                AND (CAST({} AS INTEGER) IS NULL OR "Project"."id" = CAST({} AS INTEGER))
+            )
+            SELECT * FROM (
+                SELECT 'project' AS "kind", p."id" AS "id", p."id" AS "project_id", CAST(NULL AS INTEGER) AS "task_id", p."name" AS "title",
+                    ts_headline('russian', p."name" || E'\n' || p."description", "query"."q", "query"."options") AS "snippet",
+                    CAST(ts_rank(p."searchVector", "query"."q") AS DOUBLE PRECISION) AS "rank"
+                FROM "Project" p, "query"
+                WHERE p."id" IN (SELECT "id" FROM "projects") AND p."searchVector" @@ "query"."q"
+                UNION ALL
+                SELECT 'task', t."id", t."projectId", t."id", t."name",
+                    ts_headline('russian', t."name" || E'\n' || t."description", "query"."q", "query"."options"),
+                    CAST(ts_rank(t."searchVector", "query"."q") AS DOUBLE PRECISION)
+                FROM "Task" t, "query"
+                WHERE t."projectId" IN (SELECT "id" FROM "projects") AND t."searchVector" @@ "query"."q"
+                UNION ALL
+                SELECT 'comment', c."id", t."projectId", t."id", t."name",
+                    ts_headline('russian', c."body", "query"."q", "query"."options"),
+                    CAST(ts_rank(c."searchVector", "query"."q") AS DOUBLE PRECISION)
+                FROM "Comment" c JOIN "Task" t ON t."id" = c."taskId", "query"
+                WHERE t."projectId" IN (SELECT "id" FROM "projects") AND c."deletedAt" IS NULL AND c."searchVector" @@ "query"."q"
+            ) AS "results"
+            WHERE CAST({} AS TEXT) IS NULL OR "results"."kind" = CAST({} AS TEXT)
+            ORDER BY "results"."rank" DESC, "results"."kind", "results"."id" DESC
+            LIMIT {} OFFSET {}"#,
+            PrismaValue::String(query.q.clone()),
+            PrismaValue::String(headline_options),
+            PrismaValue::Int(user_id as i64),
+            PrismaValue::Int(user_id as i64),
+            project_id.clone(),
+            project_id,
+            kind.clone(),
+            kind,
+            PrismaValue::Int(per_page as i64),
+            PrismaValue::Int(i64::try_from((page - 1).saturating_mul(per_page)).unwrap_or(i64::MAX))
+        ))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to search: {:?}", err);
+            err.to_string()
+        })?;
+
+    let items = rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(SelectSearchResult {
+                kind: SearchResultKind::from_str(&row.kind).ok()?,
+                id: row.id as u64,
+                project_id: row.project_id as u64,
+                task_id: row.task_id.map(|id| id as u64),
+                title: row.title,
+                snippet: highlight(&row.snippet),
+                rank: row.rank,
+            })
+        })
+        .collect();
+    Ok(SelectSearchResults { items, page, per_page })
+}
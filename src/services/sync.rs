@@ -0,0 +1,258 @@
+use chrono::{DateTime, Utc};
+use prisma_client_rust::Direction;
+
+use crate::models::sync::{SelectProjectSync, SelectSyncRun};
+use crate::prisma::{project, project_sync, sync_run};
+use crate::services::common::create_prisma_client;
+use crate::services::task::require_project_participant;
+use crate::services::user::is_project_owner;
+
+const LOG_TAG: &'static str = "SyncService";
+const SYNC_HISTORY_LENGTH: i64 = 20;
+
+pub fn sync_run_to_response(run: &sync_run::Data) -> SelectSyncRun {
+    SelectSyncRun {
+        id: run.id as u64,
+        started_at: run.started_at.timestamp() as u64,
+        finished_at: run.finished_at.timestamp() as u64,
+        success: run.success,
+        error: run.error.clone(),
+        items_created: run.items_created as u64,
+        items_updated: run.items_updated as u64,
+        duration_ms: run.duration_ms as u64,
+    }
+}
+
+pub fn project_sync_to_response(
+    project_id: u64,
+    sync: Option<&project_sync::Data>,
+    history: Vec<SelectSyncRun>,
+) -> SelectProjectSync {
+    match sync {
+        Some(sync) => SelectProjectSync {
+            project_id,
+            last_run_at: sync.last_run_at.map(|date| date.timestamp() as u64),
+            last_success_at: sync.last_success_at.map(|date| date.timestamp() as u64),
+            last_error: sync.last_error.clone(),
+            last_error_at: sync.last_error_at.map(|date| date.timestamp() as u64),
+            consecutive_failures: sync.consecutive_failures as u64,
+            items_created: sync.items_created as u64,
+            items_updated: sync.items_updated as u64,
+            duration_ms: sync.duration_ms as u64,
+            resync_requested: sync.requested_at.is_some(),
+            history,
+        },
+        None => SelectProjectSync {
+            project_id,
+            last_run_at: None,
+            last_success_at: None,
+            last_error: None,
+            last_error_at: None,
+            consecutive_failures: 0,
+            items_created: 0,
+            items_updated: 0,
+            duration_ms: 0,
+            resync_requested: false,
+            history,
+        },
+    }
+}
+
+pub async fn get_project_sync(user_id: u64, project_id: u64) -> Result<SelectProjectSync, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let sync = client
+        .project_sync()
+        .find_unique(project_sync::project_id::equals(project_id as i32))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project sync state: {:?}", err);
+            err.to_string()
+        })?;
+    let history = client
+        .sync_run()
+        .find_many(vec![sync_run::project_id::equals(project_id as i32)])
+        .order_by(sync_run::started_at::order(Direction::Desc))
+        .take(SYNC_HISTORY_LENGTH)
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project sync history: {:?}", err);
+            err.to_string()
+        })?;
+
+    Ok(project_sync_to_response(
+        project_id,
+        sync.as_ref(),
+        history.iter().map(sync_run_to_response).collect(),
+    ))
+}
+
+pub async fn request_project_sync(owner_id: u64, project_id: u64) -> Result<SelectProjectSync, String> {
+    if !is_project_owner(owner_id, project_id).await? {
+        log::error!(target: LOG_TAG, "User {owner_id} is not the owner of the project {project_id}");
+        return Err("User is not the owner of the project".to_string());
+    }
+
+    let client = create_prisma_client().await?;
+    let project = client
+        .project()
+        .find_unique(project::id::equals(project_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Project not found".to_string())?;
+    if project.repo_id.is_none() {
+        return Err("Project has no linked repository".to_string());
+    }
+
+    let now: DateTime<Utc> = Utc::now();
+    client
+        .project_sync()
+        .upsert(
+            project_sync::project_id::equals(project_id as i32),
+            project_sync::create(
+                project::id::equals(project_id as i32),
+                vec![project_sync::requested_at::set(Some(now.into()))],
+            ),
+            vec![project_sync::requested_at::set(Some(now.into()))],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to request project sync: {:?}", err);
+            err.to_string()
+        })?;
+
+    get_project_sync(owner_id, project_id).await
+}
+
+pub async fn get_requested_sync_project_ids() -> Result<Vec<u64>, String> {
+    let client = create_prisma_client().await?;
+    let requested = client
+        .project_sync()
+        .find_many(vec![project_sync::requested_at::not(None)])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get requested syncs: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(requested.into_iter().map(|sync| sync.project_id as u64).collect())
+}
+
+pub async fn record_sync_success(
+    project_id: u64,
+    started_at: DateTime<Utc>,
+    items_created: u64,
+    items_updated: u64,
+    duration_ms: u64,
+) -> Result<(), String> {
+    let client = create_prisma_client().await?;
+    let now: DateTime<Utc> = Utc::now();
+    let fields = || {
+        vec![
+            project_sync::last_run_at::set(Some(now.into())),
+            project_sync::last_success_at::set(Some(now.into())),
+            project_sync::consecutive_failures::set(0),
+            project_sync::items_created::set(items_created as i32),
+            project_sync::items_updated::set(items_updated as i32),
+            project_sync::duration_ms::set(duration_ms as i32),
+            project_sync::requested_at::set(None),
+        ]
+    };
+
+    client
+        .project_sync()
+        .upsert(
+            project_sync::project_id::equals(project_id as i32),
+            project_sync::create(project::id::equals(project_id as i32), fields()),
+            fields(),
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to record sync state: {:?}", err);
+            err.to_string()
+        })?;
+
+    client
+        .sync_run()
+        .create(
+            project::id::equals(project_id as i32),
+            started_at.into(),
+            true,
+            items_created as i32,
+            items_updated as i32,
+            duration_ms as i32,
+            vec![],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to record sync run: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(())
+}
+
+/// Records a failed sync and returns how many syncs in a row have failed for the project.
+pub async fn record_sync_failure(
+    project_id: u64,
+    started_at: DateTime<Utc>,
+    error: &str,
+    duration_ms: u64,
+) -> Result<u64, String> {
+    let client = create_prisma_client().await?;
+    let now: DateTime<Utc> = Utc::now();
+    let fields = || {
+        vec![
+            project_sync::last_run_at::set(Some(now.into())),
+            project_sync::last_error::set(Some(error.to_string())),
+            project_sync::last_error_at::set(Some(now.into())),
+            project_sync::items_created::set(0),
+            project_sync::items_updated::set(0),
+            project_sync::duration_ms::set(duration_ms as i32),
+            project_sync::requested_at::set(None),
+        ]
+    };
+    let mut create_fields = fields();
+    create_fields.push(project_sync::consecutive_failures::set(1));
+    let mut update_fields = fields();
+    update_fields.push(project_sync::consecutive_failures::increment(1));
+
+    let sync = client
+        .project_sync()
+        .upsert(
+            project_sync::project_id::equals(project_id as i32),
+            project_sync::create(project::id::equals(project_id as i32), create_fields),
+            update_fields,
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to record sync state: {:?}", err);
+            err.to_string()
+        })?;
+
+    client
+        .sync_run()
+        .create(
+            project::id::equals(project_id as i32),
+            started_at.into(),
+            false,
+            0,
+            0,
+            duration_ms as i32,
+            vec![sync_run::error::set(Some(error.to_string()))],
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to record sync run: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(sync.consecutive_failures as u64)
+}
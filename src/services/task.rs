@@ -1,29 +1,61 @@
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::vec;
 
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use prisma_client_rust::{Direction, QueryError};
+use prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation;
 
 use crate::models::{
-    task::{CreateTaskRequest, SelectTask, SelectTaskRequest, UpdateTaskRequest},
+    actor::Actor,
+    task::{CreateTaskRequest, SelectMyTasksRequest, SelectTask, SelectTaskPage, SelectTaskRequest, SortDirection, TaskSortField, UpdateTaskRequest},
     user::SelectUser,
 };
 use crate::models::project::SelectProject;
-use crate::models::task::TaskStatus;
-use crate::prisma::{project, task, user};
+use crate::models::task::{CreateSubtaskRequest, DeleteTaskQuery, ReorderSubtasksRequest, SubtaskProgress, TaskPriority};
+use crate::models::workflow::StatusCategory;
+use crate::prisma::{label, project, project_status, task, user, PrismaClient, QueryMode};
 use crate::prisma::task::Data;
-use crate::services::common::create_prisma_client;
-use crate::services::user::{is_project_member, is_project_owner, user_data_to_response};
+use crate::services::board::next_board_position;
+use crate::services::common::{create_prisma_client, VersionedUpdateError};
+use crate::services::history::record_task_changes;
+use crate::services::label::{label_to_response, require_project_labels};
+use crate::services::time_entry::total_time_spent;
+use crate::services::project::repository_provider_from_str;
+use crate::services::user::{actor_to_response, is_project_member, is_project_owner, user_data_to_response};
+use crate::services::workflow::{check_transition, get_project_statuses, initial_status, status_category};
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+pub const TASK_CHANGED: &'static str = "Task was changed since the given version";
+
+/// Error of a task list query. Invalid filters are the caller's fault, other failures are not.
+pub enum TaskQueryError {
+    InvalidFilters(String),
+    /// The filtered project doesn't exist or the user doesn't participate in it, which look the same
+    ProjectNotFound,
+    Failed(String),
+}
+
+impl From<String> for TaskQueryError {
+    fn from(err: String) -> Self {
+        TaskQueryError::Failed(err)
+    }
+}
 
 pub async fn task_data_to_response(task_item: &Data) -> Result<SelectTask, String> {
+    let statuses = match task_item.project.as_ref().and_then(|project| project.statuses.as_ref()) {
+        Some(statuses) => statuses,
+        None => return Err("Failed to fetch project statuses".to_string()),
+    };
     // Converts from ORM model to response model
     Ok(SelectTask {
         id: task_item.id as u64,
         created_at: task_item.created_at.timestamp() as u64,
         name: task_item.name.clone(),
         description: task_item.description.clone(),
-        status: TaskStatus::from_str(&task_item.status).unwrap(),
+        status: task_item.status.clone(),
+        status_category: status_category(statuses, &task_item.status),
         due_date: match task_item.due_date {
             Some(date) => Some(date.timestamp() as u64),
             None => None,
@@ -49,12 +81,164 @@ pub async fn task_data_to_response(task_item: &Data) -> Result<SelectTask, Strin
                 members: vec![],
                 tasks: vec![],
                 repository_id: project.repo_id.clone(),
+                repository_provider: repository_provider_from_str(&project.repo_provider),
+                repository_base_url: project.repo_base_url.clone(),
+                version: project.version as u64,
             },
             None => return Err("Failed to fetch project".to_string()),
         },
+        created_by: actor_to_response(
+            task_item.created_by.as_ref().and_then(|user| user.as_deref()),
+            task_item.created_by_system.as_deref(),
+        ),
+        updated_by: actor_to_response(
+            task_item.updated_by.as_ref().and_then(|user| user.as_deref()),
+            task_item.updated_by_system.as_deref(),
+        ),
+        parent_id: task_item.parent_id.map(|id| id as u64),
+        position: task_item.position as u64,
+        board_position: task_item.board_position,
+        subtasks: match &task_item.children {
+            Some(children) => subtask_progress(children, statuses),
+            None => return Err("Failed to fetch subtasks".to_string()),
+        },
+        priority: TaskPriority::from_str(&task_item.priority).unwrap_or(TaskPriority::None),
+        estimate: task_item.estimate,
+        labels: match &task_item.labels {
+            Some(labels) => labels.iter().map(label_to_response).collect(),
+            None => return Err("Failed to fetch labels".to_string()),
+        },
+        time_spent: match &task_item.time_entries {
+            Some(entries) => total_time_spent(entries),
+            None => return Err("Failed to fetch time entries".to_string()),
+        },
+        recurrence_id: task_item.recurrence_id.map(|id| id as u64),
+        version: task_item.version as u64,
     })
 }
 
+fn subtask_progress(children: &[Data], statuses: &[project_status::Data]) -> SubtaskProgress {
+    let categories: Vec<StatusCategory> = children
+        .iter()
+        .map(|child| status_category(statuses, &child.status))
+        .filter(|category| *category != StatusCategory::Cancelled)
+        .collect();
+    SubtaskProgress {
+        done: categories.iter().filter(|category| **category == StatusCategory::Done).count() as u64,
+        total: categories.len() as u64,
+    }
+}
+
+/// Every task below the given one, level by level.
+pub async fn collect_descendants(client: &PrismaClient, task_id: u64) -> Result<Vec<Data>, QueryError> {
+    let mut descendants = vec![];
+    let mut frontier = vec![task_id as i32];
+    while !frontier.is_empty() {
+        let children = client
+            .task()
+            .find_many(vec![task::parent_id::in_vec(frontier)])
+            .exec()
+            .await?;
+        frontier = children.iter().map(|child| child.id).collect();
+        descendants.extend(children);
+    }
+    Ok(descendants)
+}
+
+/// Position that puts a task after its current siblings.
+pub async fn next_position(client: &PrismaClient, project_id: u64, parent_id: Option<u64>) -> Result<i32, QueryError> {
+    let last = client
+        .task()
+        .find_first(vec![
+            task::project_id::equals(project_id as i32),
+            task::parent_id::equals(parent_id.map(|id| id as i32)),
+        ])
+        .order_by(task::position::order(Direction::Desc))
+        .exec()
+        .await?;
+    Ok(last.map_or(0, |task| task.position + 1))
+}
+
+fn created_by_params(actor: &Actor) -> Vec<task::SetParam> {
+    match actor {
+        Actor::User(user_id) => vec![task::created_by::connect(user::id::equals(*user_id as i32))],
+        Actor::System(system) => vec![task::created_by_system::set(Some(system.to_string()))],
+    }
+}
+
+/// Who changed a task last. Every change goes through here, so it also moves the task to its next version.
+pub fn updated_by_params(actor: &Actor) -> Vec<task::SetParam> {
+    match actor {
+        Actor::User(user_id) => vec![
+            task::updated_by::connect(user::id::equals(*user_id as i32)),
+            task::updated_by_system::set(None),
+            task::version::increment(1),
+        ],
+        Actor::System(system) => vec![
+            task::updated_by::disconnect(),
+            task::updated_by_system::set(Some(system.to_string())),
+            task::version::increment(1),
+        ],
+    }
+}
+
+/// Open subtasks of a task a user moves to `status`. Subtasks left open under a finished task would
+/// drop out of sight, so completing a task with open subtasks has to be confirmed.
+pub async fn open_subtasks_to_complete(
+    client: &PrismaClient,
+    task_id: u64,
+    statuses: &[project_status::Data],
+    status: &str,
+    confirmed: bool,
+) -> Result<Vec<Data>, String> {
+    if status_category(statuses, status) != StatusCategory::Done {
+        return Ok(vec![]);
+    }
+    let open_subtasks: Vec<Data> = collect_descendants(client, task_id).await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .filter(|subtask| !status_category(statuses, &subtask.status).is_finished())
+        .collect();
+    if !open_subtasks.is_empty() && !confirmed {
+        return Err(format!(
+            "Task has {} open subtasks. Set complete_subtasks to complete them along with the task",
+            open_subtasks.len()
+        ));
+    }
+    Ok(open_subtasks)
+}
+
+/// Moves subtasks to the status their parent is completed with.
+pub async fn complete_subtasks(client: &PrismaClient, actor: &Actor, subtasks: &[Data], status: &str) -> Result<(), QueryError> {
+    if subtasks.is_empty() {
+        return Ok(());
+    }
+    let mut properties = vec![task::status::set(status.to_string())];
+    properties.extend(updated_by_params(actor));
+    client
+        .task()
+        .update_many(vec![task::id::in_vec(subtasks.iter().map(|subtask| subtask.id).collect())], properties)
+        .exec()
+        .await
+        .map(|_| ())
+}
+
+/// Records the completion of subtasks done by `complete_subtasks`.
+pub async fn record_completed_subtasks(actor: &Actor, subtasks: &[Data], status: &str) {
+    for subtask in subtasks {
+        let mut completed = subtask.clone();
+        completed.status = status.to_string();
+        record_task_changes(actor, subtask, &completed).await;
+    }
+}
+
+fn query_error_to_string(err: QueryError) -> String {
+    if err.is_prisma_error::<UniqueKeyViolation>() {
+        return "Another task of the project is already linked to this issue".to_string();
+    }
+    err.to_string()
+}
+
 pub async fn task_entity_to_response(task: prisma_client_rust::Result<Data>) -> Result<Option<Vec<SelectUser>>, String> {
     match task {
         Ok(task) => {
@@ -91,6 +275,14 @@ pub async fn require_project_participant(user_id: u64, project_id: u64) -> Resul
     Ok(())
 }
 
+pub async fn require_actor_participant(actor: &Actor, project_id: u64) -> Result<(), String> {
+    match actor {
+        Actor::User(user_id) => require_project_participant(*user_id, project_id).await,
+        // Integrations act on behalf of the project they are configured for
+        Actor::System(_) => Ok(()),
+    }
+}
+
 pub async fn check_member_from_task(user_id: u64, task_id: u64) -> Result<(), String> {
     let client = create_prisma_client().await?;
     let project_id = match client
@@ -105,38 +297,193 @@ pub async fn check_member_from_task(user_id: u64, task_id: u64) -> Result<(), St
     };
     require_project_participant(user_id, project_id).await
 }
+fn task_order(sort: TaskSortField, direction: Direction) -> task::OrderByWithRelationParam {
+    match sort {
+        TaskSortField::DueDate => task::due_date::order(direction),
+        TaskSortField::CreatedAt => task::created_at::order(direction),
+        TaskSortField::Name => task::name::order(direction),
+        TaskSortField::Estimate => task::estimate::order(direction),
+        TaskSortField::BoardPosition => task::board_position::order(direction),
+        TaskSortField::Id => task::id::order(direction),
+    }
+}
+
+fn timestamp_filter(timestamp: u64, name: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::from_timestamp(timestamp as i64, 0).ok_or_else(|| format!("Invalid {}", name))
+}
+
+/// Tasks whose status is in a Done or Cancelled category of their project.
+async fn finished_tasks_filter(client: &PrismaClient, project_id: Option<u64>) -> Result<Option<task::WhereParam>, String> {
+    let mut status_filters = vec![project_status::category::in_vec(vec![
+        StatusCategory::Done.to_string(),
+        StatusCategory::Cancelled.to_string(),
+    ])];
+    if let Some(project_id) = project_id {
+        status_filters.push(project_status::project_id::equals(project_id as i32));
+    }
+    let statuses = client
+        .project_status()
+        .find_many(status_filters)
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut keys: HashMap<i32, Vec<String>> = HashMap::new();
+    for status in statuses {
+        keys.entry(status.project_id).or_default().push(status.key);
+    }
+    if keys.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(prisma_client_rust::operator::or(
+        keys.into_iter()
+            .map(|(project_id, keys)| {
+                prisma_client_rust::and![task::project_id::equals(project_id), task::status::in_vec(keys)]
+            })
+            .collect(),
+    )))
+}
+
 pub async fn get_user_tasks(
     user_id: u64,
-    filters: &SelectTaskRequest,
+    filters: &SelectMyTasksRequest,
 ) -> Result<Vec<SelectTask>, String> {
     let client = create_prisma_client().await?;
-    let mut query_filters = vec![task::attached_to::some(vec![user::id::equals(
-        user_id as i32,
-    )])];
-    if filters.project_id.is_some() {
-        query_filters.push(task::attached_to::some(vec![user::id::equals(
-            filters.project_id.unwrap() as i32,
-        )]));
+    let mut query_filters = vec![task::attached_to::some(vec![user::id::equals(user_id as i32)])];
+    if let Some(project_id) = filters.project_id {
+        query_filters.push(task::project_id::equals(project_id as i32));
     }
-    let tasks_query = client
+    let fetched_tasks = client
         .task()
         .find_many(query_filters)
         .with(task::attached_to::fetch(vec![]))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
         .order_by(task::due_date::order(Direction::Asc))
-        .with(task::project::fetch().with(project::owner::fetch()))
         .exec()
-        .await;
+        .await
+        .map_err(|err| err.to_string())?;
 
-    match tasks_query {
-        Ok(fetched_tasks) => {
-            let mut rendered_tasks: Vec<SelectTask> = vec![];
-            for task in fetched_tasks.iter() {
-                rendered_tasks.push(task_data_to_response(task).await?);
+    let mut rendered_tasks: Vec<SelectTask> = vec![];
+    for task in fetched_tasks.iter() {
+        rendered_tasks.push(task_data_to_response(task).await?);
+    }
+    Ok(rendered_tasks)
+}
+
+/// Lists the tasks assigned to the user, or all tasks of a project the user participates in.
+/// Pages follow each other by the id of the last task, so inserts don't shift them.
+pub async fn get_tasks(user_id: u64, filters: &SelectTaskRequest) -> Result<SelectTaskPage, TaskQueryError> {
+    let client = create_prisma_client().await?;
+    let mut query_filters = match filters.project_id {
+        Some(project_id) => {
+            if !is_project_member(user_id, project_id).await? && !is_project_owner(user_id, project_id).await? {
+                return Err(TaskQueryError::ProjectNotFound);
             }
-            Ok(rendered_tasks)
+            vec![task::project_id::equals(project_id as i32)]
         }
-        Err(err) => Err(err.to_string()),
+        None => vec![task::attached_to::some(vec![user::id::equals(user_id as i32)])],
+    };
+    if let Some(status) = &filters.status {
+        let keys: Vec<String> = status
+            .split(',')
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty())
+            .collect();
+        query_filters.push(task::status::in_vec(keys));
+    }
+    if let Some(assignee_id) = filters.assignee_id {
+        query_filters.push(task::attached_to::some(vec![user::id::equals(assignee_id as i32)]));
+    }
+    if filters.unassigned.unwrap_or(false) {
+        query_filters.push(task::attached_to::none(vec![]));
+    }
+    if let Some(due_from) = filters.due_from {
+        let due_from = timestamp_filter(due_from, "start of the due date range").map_err(TaskQueryError::InvalidFilters)?;
+        query_filters.push(task::due_date::gte(due_from.into()));
+    }
+    if let Some(due_to) = filters.due_to {
+        let due_to = timestamp_filter(due_to, "end of the due date range").map_err(TaskQueryError::InvalidFilters)?;
+        query_filters.push(task::due_date::lte(due_to.into()));
+    }
+    if filters.overdue.unwrap_or(false) {
+        query_filters.push(task::due_date::lt(Utc::now().into()));
+        if let Some(finished) = finished_tasks_filter(&client, filters.project_id).await? {
+            query_filters.push(prisma_client_rust::not![finished]);
+        }
+    }
+    if let Some(assigned_issue) = filters.assigned_issue {
+        query_filters.push(task::assigned_issue::equals(Some(assigned_issue as i32)));
     }
+    match filters.has_issue {
+        Some(true) => query_filters.push(task::assigned_issue::not(None)),
+        Some(false) => query_filters.push(task::assigned_issue::equals(None)),
+        None => {}
+    }
+    if let Some(text) = &filters.q {
+        query_filters.push(prisma_client_rust::or![
+            prisma_client_rust::and![
+                task::name::contains(text.clone()),
+                task::name::mode(QueryMode::Insensitive)
+            ],
+            prisma_client_rust::and![
+                task::description::contains(text.clone()),
+                task::description::mode(QueryMode::Insensitive)
+            ]
+        ]);
+    }
+    if let Some(priority) = filters.priority {
+        query_filters.push(task::priority::equals(priority.to_string()));
+    }
+    if let Some(label_id) = filters.label_id {
+        query_filters.push(task::labels::some(vec![label::id::equals(label_id as i32)]));
+    }
+    if let Some(min_estimate) = filters.min_estimate {
+        query_filters.push(task::estimate::gte(min_estimate));
+    }
+    if let Some(max_estimate) = filters.max_estimate {
+        query_filters.push(task::estimate::lte(max_estimate));
+    }
+
+    let direction = match filters.direction.unwrap_or_default() {
+        SortDirection::Asc => Direction::Asc,
+        SortDirection::Desc => Direction::Desc,
+    };
+    let limit = filters.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    // One task more than the page tells whether another page follows
+    let mut tasks_query = client
+        .task()
+        .find_many(query_filters)
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
+        .order_by(task_order(filters.sort.unwrap_or_default(), direction.clone()))
+        .order_by(task::id::order(direction))
+        .take(limit as i64 + 1);
+    if let Some(cursor) = filters.cursor {
+        tasks_query = tasks_query.cursor(task::id::equals(cursor as i32)).skip(1);
+    }
+    let mut fetched_tasks = tasks_query.exec().await.map_err(|err| err.to_string())?;
+
+    let next_cursor = if fetched_tasks.len() as u64 > limit {
+        fetched_tasks.truncate(limit as usize);
+        fetched_tasks.last().map(|task| task.id as u64)
+    } else {
+        None
+    };
+    let mut items: Vec<SelectTask> = vec![];
+    for task in fetched_tasks.iter() {
+        items.push(task_data_to_response(task).await?);
+    }
+    Ok(SelectTaskPage { items, next_cursor })
 }
 
 pub async fn get_task_by_id(task_id: u64) -> Result<Option<SelectTask>, String> {
@@ -145,34 +492,101 @@ pub async fn get_task_by_id(task_id: u64) -> Result<Option<SelectTask>, String>
         .task()
         .find_first(vec![task::id::equals(task_id as i32)])
         .with(task::attached_to::fetch(vec![]))
-        .with(task::project::fetch().with(project::owner::fetch()))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
         .exec()
         .await;
     task_result_to_response(task).await
 }
 
-pub async fn create_task(user_id: u64, task: &CreateTaskRequest) -> Result<SelectTask, String> {
-    require_project_participant(user_id, task.project_id).await?;
+pub async fn get_task_by_issue(project_id: u64, issue: u64) -> Result<Option<SelectTask>, String> {
+    let client = create_prisma_client().await?;
+    let task = client
+        .task()
+        .find_first(vec![
+            task::project_id::equals(project_id as i32),
+            task::assigned_issue::equals(Some(issue as i32)),
+        ])
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
+        .exec()
+        .await;
+    task_result_to_response(task).await
+}
+
+pub async fn create_task(actor: Actor, task: &CreateTaskRequest) -> Result<SelectTask, String> {
+    require_actor_participant(&actor, task.project_id).await?;
 
     let client = create_prisma_client().await?;
+    if let Some(parent_id) = task.parent_id {
+        let parent = client
+            .task()
+            .find_unique(task::id::equals(parent_id as i32))
+            .exec()
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| "Parent task not found".to_string())?;
+        if parent.project_id as u64 != task.project_id {
+            return Err("Parent task belongs to another project".to_string());
+        }
+    }
+    let position = next_position(&client, task.project_id, task.parent_id).await
+        .map_err(|err| err.to_string())?;
+    require_project_labels(task.project_id, &task.label_ids).await?;
+    let status = initial_status(&get_project_statuses(task.project_id).await?);
+    let board_position = next_board_position(&client, task.project_id, &status).await
+        .map_err(|err| err.to_string())?;
+
+    let mut create_properties = vec![
+        task::attached_to::connect(
+            task.attached_to
+                .iter()
+                .map(|id| user::id::equals(*id as i32))
+                .collect(),
+        ),
+        task::assigned_issue::set(task.assigned_issue.map(|issue| issue as i32)),
+        task::position::set(position),
+        task::board_position::set(board_position),
+        task::priority::set(task.priority.unwrap_or(TaskPriority::None).to_string()),
+        task::estimate::set(task.estimate),
+        task::labels::connect(task.label_ids.iter().map(|id| label::id::equals(*id as i32)).collect()),
+    ];
+    let mut watcher_ids = task.attached_to.clone();
+    watcher_ids.extend(actor.user_id());
+    create_properties.push(task::watchers::connect(watcher_ids.iter().map(|id| user::id::equals(*id as i32)).collect()));
+    if let Some(due_date) = task.due_date {
+        let date = DateTime::from_timestamp(due_date as i64, 0).ok_or_else(|| "Invalid due date".to_string())?;
+        create_properties.push(task::due_date::set(Some(date.into())));
+    }
+    if let Some(parent_id) = task.parent_id {
+        create_properties.push(task::parent::connect(task::id::equals(parent_id as i32)));
+    }
+    create_properties.extend(created_by_params(&actor));
     let task = client
         .task()
         .create(
             task.name.clone(),
-            TaskStatus::Todo.to_string(),
+            status,
             task.description.clone(),
             project::id::equals(task.project_id as i32),
-            vec![task::attached_to::connect(
-                task.attached_to
-                    .iter()
-                    .map(|id| user::id::equals(*id as i32))
-                    .collect(),
-            ),
-                 task::assigned_issue::set(task.assigned_issue.map(|issue| issue as i32)),
-            ],
+            create_properties,
         )
         .with(task::attached_to::fetch(vec![]))
-        .with(task::project::fetch().with(project::owner::fetch()))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
         .exec()
         .await;
 
@@ -181,31 +595,88 @@ pub async fn create_task(user_id: u64, task: &CreateTaskRequest) -> Result<Selec
             let task = task_data_to_response(&created_task).await?;
             Ok(task)
         }
-        Err(err) => Err(err.to_string()),
+        Err(err) => Err(query_error_to_string(err)),
     }
 }
 
+/// Updates a task. With `expected_version` the update only applies to that version of the task.
 pub async fn update_task(
-    user_id: u64,
+    actor: Actor,
     task_id: u64,
     task: &UpdateTaskRequest,
-) -> Result<Option<SelectTask>, String> {
-    check_member_from_task(user_id, task_id).await?;
+    expected_version: Option<u64>,
+) -> Result<Option<SelectTask>, VersionedUpdateError> {
+    if let Actor::User(user_id) = actor {
+        check_member_from_task(user_id, task_id).await?;
+    }
 
     let client = create_prisma_client().await?;
+    let existing = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(existing) => existing,
+        None => return Ok(None),
+    };
+    if expected_version.map_or(false, |version| version != existing.version as u64) {
+        return Err(VersionedUpdateError::Changed);
+    }
+    let statuses = get_project_statuses(existing.project_id as u64).await?;
+
+    if let Some(status) = &task.status {
+        // Integrations follow the state of the issue tracker rather than the project workflow
+        match actor {
+            Actor::User(_) => check_transition(existing.project_id as u64, &existing.status, status).await?,
+            Actor::System(_) if !statuses.iter().any(|project_status| project_status.key == *status) => {
+                return Err(format!("Project has no status {}", status).into());
+            }
+            Actor::System(_) => {}
+        }
+    }
+
+    // A closed issue is final, so the repository sync completes the task regardless of its subtasks
+    let open_subtasks = match (&task.status, actor) {
+        (Some(status), Actor::User(_)) => {
+            open_subtasks_to_complete(&client, task_id, &statuses, status, task.complete_subtasks.unwrap_or(false)).await?
+        }
+        _ => vec![],
+    };
+    let completed_subtasks = open_subtasks.clone();
+    let new_status = task.status.clone().unwrap_or_default();
 
-    let mut update_properties = vec![];
+    let mut update_properties = updated_by_params(&actor);
     if let Some(name) = task.name.clone() {
         update_properties.push(task::name::set(name));
     }
-    if let Some(status) = task.status.clone() {
-        update_properties.push(task::status::set(status.to_string()));
+    if let Some(status) = task.status.clone().filter(|status| *status != existing.status) {
+        // A task changing columns goes to the bottom of its new column
+        let board_position = next_board_position(&client, existing.project_id as u64, &status).await
+            .map_err(|err| err.to_string())?;
+        update_properties.push(task::board_position::set(board_position));
+        update_properties.push(task::status::set(status));
+        // A status set by hand replaces the one an automatic block would restore
+        update_properties.push(task::status_before_block::set(None));
     }
     if let Some(description) = task.description.clone() {
         update_properties.push(task::description::set(description));
     }
+    if let Some(priority) = task.priority {
+        update_properties.push(task::priority::set(priority.to_string()));
+    }
+    if let Some(estimate) = task.estimate {
+        update_properties.push(task::estimate::set(Some(estimate)));
+    }
+    if let Some(label_ids) = &task.label_ids {
+        require_project_labels(existing.project_id as u64, label_ids).await?;
+        update_properties.push(task::labels::set(label_ids.iter().map(|id| label::id::equals(*id as i32)).collect()));
+    }
     if let Some(due_date) = task.due_date {
-        let date = DateTime::from_timestamp(due_date as i64, 0).unwrap();
+        let date = DateTime::from_timestamp(due_date as i64, 0).ok_or_else(|| "Invalid due date".to_string())?;
         update_properties.push(task::due_date::set(Some(date.into())));
     }
     if let Some(assigned_issue) = task.assigned_issue {
@@ -215,31 +686,201 @@ pub async fn update_task(
     }
 
     let task = client
+        ._transaction()
+        .run(|tx| async move {
+            // Locks the task until the update commits, so a concurrent update based on the same version misses it
+            if let Some(version) = expected_version {
+                let claimed = tx
+                    .task()
+                    .update_many(
+                        vec![task::id::equals(task_id as i32), task::version::equals(version as i32)],
+                        vec![task::version::set(version as i32)],
+                    )
+                    .exec()
+                    .await?;
+                if claimed == 0 {
+                    return Ok(None);
+                }
+            }
+            complete_subtasks(&tx, &actor, &completed_subtasks, &new_status).await?;
+            tx.task()
+                .update(task::id::equals(task_id as i32), update_properties)
+                .with(task::attached_to::fetch(vec![]))
+                .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+                .with(task::created_by::fetch())
+                .with(task::updated_by::fetch())
+                .with(task::children::fetch(vec![]))
+                .with(task::labels::fetch(vec![]))
+                .with(task::time_entries::fetch(vec![]))
+                .exec()
+                .await
+                .map(Some)
+        })
+        .await;
+    match task {
+        Ok(Some(updated_task)) => {
+            record_completed_subtasks(&actor, &open_subtasks, &updated_task.status).await;
+            record_task_changes(&actor, &existing, &updated_task).await;
+            Ok(task_result_to_response(Ok(Some(updated_task))).await?)
+        }
+        Ok(None) => Err(VersionedUpdateError::Changed),
+        Err(err) => Err(query_error_to_string(err).into()),
+    }
+}
+
+/// Deletes a task. Its subtasks are deleted with it when `cascade` is set,
+/// otherwise they move to the parent of the deleted task, after its other subtasks.
+pub async fn delete_task(user_id: u64, task_id: u64, query: &DeleteTaskQuery) -> Result<(), String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let deleted = client
         .task()
-        .update(task::id::equals(task_id as i32), update_properties)
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+
+    if query.cascade.unwrap_or(false) {
+        let mut ids: Vec<i32> = collect_descendants(&client, task_id).await
+            .map_err(|err| err.to_string())?
+            .iter()
+            .map(|subtask| subtask.id)
+            .collect();
+        ids.push(deleted.id);
+        return client
+            .task()
+            .delete_many(vec![task::id::in_vec(ids)])
+            .exec()
+            .await
+            .map(|_| ())
+            .map_err(|err| err.to_string());
+    }
+
+    client
+        ._transaction()
+        .run(|tx| async move { delete_keeping_subtasks(&tx, deleted.id).await })
+        .await
+        .map_err(|err: QueryError| err.to_string())
+}
+
+/// Deletes a task, moving its subtasks to its parent after the parent's other subtasks.
+pub async fn delete_keeping_subtasks(client: &PrismaClient, task_id: i32) -> Result<(), QueryError> {
+    let deleted = match client.task().find_unique(task::id::equals(task_id)).exec().await? {
+        Some(deleted) => deleted,
+        None => return Ok(()),
+    };
+    let children = client
+        .task()
+        .find_many(vec![task::parent_id::equals(Some(deleted.id))])
+        .order_by(task::position::order(Direction::Asc))
+        .exec()
+        .await?;
+    let first_position = next_position(client, deleted.project_id as u64, deleted.parent_id.map(|id| id as u64)).await?;
+
+    for (index, child) in children.iter().enumerate() {
+        client
+            .task()
+            .update(
+                task::id::equals(child.id),
+                vec![
+                    task::parent_id::set(deleted.parent_id),
+                    task::position::set(first_position + index as i32),
+                ],
+            )
+            .exec()
+            .await?;
+    }
+    client.task().delete(task::id::equals(deleted.id)).exec().await?;
+    Ok(())
+}
+
+pub async fn get_subtasks(user_id: u64, task_id: u64) -> Result<Vec<SelectTask>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let subtasks = client
+        .task()
+        .find_many(vec![task::parent_id::equals(Some(task_id as i32))])
         .with(task::attached_to::fetch(vec![]))
-        .with(task::project::fetch().with(project::owner::fetch()))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
+        .with(task::created_by::fetch())
+        .with(task::updated_by::fetch())
+        .with(task::children::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .with(task::time_entries::fetch(vec![]))
+        .order_by(task::position::order(Direction::Asc))
         .exec()
-        .await;
-    match task {
-        Ok(updated_task) => task_result_to_response(Ok(Some(updated_task))).await,
-        Err(err) => Err(err.to_string()),
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut rendered_subtasks = vec![];
+    for subtask in &subtasks {
+        rendered_subtasks.push(task_data_to_response(subtask).await?);
     }
+    Ok(rendered_subtasks)
+}
+
+pub async fn create_subtask(user_id: u64, parent_id: u64, subtask: &CreateSubtaskRequest) -> Result<SelectTask, String> {
+    let client = create_prisma_client().await?;
+    let parent = client
+        .task()
+        .find_unique(task::id::equals(parent_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+
+    create_task(Actor::User(user_id), &CreateTaskRequest {
+        name: subtask.name.clone(),
+        description: subtask.description.clone(),
+        project_id: parent.project_id as u64,
+        attached_to: subtask.attached_to.clone(),
+        due_date: subtask.due_date,
+        assigned_issue: None,
+        parent_id: Some(parent_id),
+        priority: subtask.priority,
+        estimate: subtask.estimate,
+        label_ids: subtask.label_ids.clone(),
+    }).await
 }
 
-pub async fn delete_task(user_id: u64, task_id: u64) -> Result<(), String> {
+pub async fn reorder_subtasks(user_id: u64, task_id: u64, order: &ReorderSubtasksRequest) -> Result<Vec<SelectTask>, String> {
     check_member_from_task(user_id, task_id).await?;
 
     let client = create_prisma_client().await?;
-    let task = client
+    let children = client
         .task()
-        .delete(task::id::equals(task_id as i32))
+        .find_many(vec![task::parent_id::equals(Some(task_id as i32))])
         .exec()
-        .await;
-    match task {
-        Ok(_) => Ok(()),
-        Err(err) => Err(err.to_string()),
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut current: Vec<u64> = children.iter().map(|child| child.id as u64).collect();
+    let mut requested = order.task_ids.clone();
+    current.sort_unstable();
+    requested.sort_unstable();
+    if current != requested {
+        return Err("task_ids must list every subtask of the task exactly once".to_string());
     }
+
+    let task_ids = order.task_ids.clone();
+    client
+        ._transaction()
+        .run(|tx| async move {
+            for (position, id) in task_ids.into_iter().enumerate() {
+                tx.task()
+                    .update(task::id::equals(id as i32), vec![task::position::set(position as i32)])
+                    .exec()
+                    .await?;
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|err: QueryError| err.to_string())?;
+
+    get_subtasks(user_id, task_id).await
 }
 
 pub async fn add_assigned_user(
@@ -249,18 +890,29 @@ pub async fn add_assigned_user(
 ) -> Result<Option<Vec<SelectUser>>, String> {
     check_member_from_task(user_id, task_id).await?;
     let client = create_prisma_client().await?;
+    let before = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::attached_to::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut update_properties = vec![
+        task::attached_to::connect(vec![user::id::equals(assigned_user_id as i32)]),
+        task::watchers::connect(vec![user::id::equals(assigned_user_id as i32)]),
+    ];
+    update_properties.extend(updated_by_params(&Actor::User(user_id)));
+
     let task = client
         .task()
-        .update(
-            task::id::equals(task_id as i32),
-            vec![task::attached_to::connect(vec![user::id::equals(
-                assigned_user_id as i32,
-            )])],
-        )
+        .update(task::id::equals(task_id as i32), update_properties)
         .with(task::attached_to::fetch(vec![]))
-        .with(task::project::fetch().with(project::owner::fetch()))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
         .exec()
         .await;
+    if let (Some(before), Ok(after)) = (&before, &task) {
+        record_task_changes(&Actor::User(user_id), before, after).await;
+    }
     task_entity_to_response(task).await
 }
 
@@ -272,17 +924,27 @@ pub async fn remove_assigned_user(
     check_member_from_task(user_id, task_id).await?;
     let client = create_prisma_client().await?;
 
+    let before = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::attached_to::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut update_properties = vec![task::attached_to::disconnect(vec![user::id::equals(
+        assigned_user_id as i32,
+    )])];
+    update_properties.extend(updated_by_params(&Actor::User(user_id)));
+
     let task = client
         .task()
-        .update(
-            task::id::equals(task_id as i32),
-            vec![task::attached_to::disconnect(vec![user::id::equals(
-                assigned_user_id as i32,
-            )])],
-        )
+        .update(task::id::equals(task_id as i32), update_properties)
         .with(task::attached_to::fetch(vec![]))
-        .with(task::project::fetch().with(project::owner::fetch()))
+        .with(task::project::fetch().with(project::owner::fetch()).with(project::statuses::fetch(vec![])))
         .exec()
         .await;
+    if let (Some(before), Ok(after)) = (&before, &task) {
+        record_task_changes(&Actor::User(user_id), before, after).await;
+    }
     task_entity_to_response(task).await
 }
@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use prisma_client_rust::{Direction, QueryError};
+
+use crate::models::actor::Actor;
+use crate::models::project::{CreateProjectRequest, SelectProject};
+use crate::models::task::{CreateTaskRequest, SelectTask, TaskPriority};
+use crate::models::template::{
+    CreateProjectFromTemplateRequest, CreateProjectTemplateRequest, CreateTaskFromTemplateRequest,
+    SaveProjectTemplateRequest, SelectProjectTemplate, SelectTaskTemplate, SelectTemplateRole, TaskTemplateRequest,
+    TemplateRoleRequest,
+};
+use crate::prisma::{project, project_template, task, task_template, template_role, user};
+use crate::services::common::create_prisma_client;
+use crate::services::project::{create_project, delete_project, get_project_by_id};
+use crate::services::task::{create_task, require_project_participant};
+use crate::services::user::{is_project_member, is_project_owner, user_data_to_response};
+
+const LOG_TAG: &'static str = "TemplateService";
+
+pub fn task_template_to_response(template: &task_template::Data) -> SelectTaskTemplate {
+    SelectTaskTemplate {
+        id: template.id as u64,
+        project_template_id: template.project_template_id.map(|id| id as u64),
+        name: template.name.clone(),
+        description: template.description.clone(),
+        priority: TaskPriority::from_str(&template.priority).unwrap_or(TaskPriority::None),
+        estimate: template.estimate,
+        assignee_roles: template.assignee_roles.clone(),
+        due_offset_days: template.due_offset_days.map(|days| days as u64),
+        position: template.position as u64,
+    }
+}
+
+pub fn project_template_to_response(template: &project_template::Data) -> Result<SelectProjectTemplate, String> {
+    let roles = match &template.roles {
+        Some(roles) => roles
+            .iter()
+            .map(|role| SelectTemplateRole {
+                name: role.name.clone(),
+                default_user: role.default_user.as_ref().and_then(|user| user.as_deref()).map(user_data_to_response),
+            })
+            .collect(),
+        None => return Err("Failed to fetch template roles".to_string()),
+    };
+    let tasks = match &template.tasks {
+        Some(tasks) => tasks.iter().map(task_template_to_response).collect(),
+        None => return Err("Failed to fetch template tasks".to_string()),
+    };
+    Ok(SelectProjectTemplate {
+        id: template.id as u64,
+        created_at: template.created_at.timestamp() as u64,
+        name: template.name.clone(),
+        description: template.description.clone(),
+        roles,
+        tasks,
+    })
+}
+
+fn task_template_params(request: &TaskTemplateRequest, position: i32) -> Vec<task_template::SetParam> {
+    vec![
+        task_template::priority::set(request.priority.unwrap_or(TaskPriority::None).to_string()),
+        task_template::estimate::set(request.estimate),
+        task_template::assignee_roles::set(request.assignee_roles.clone()),
+        task_template::due_offset_days::set(request.due_offset_days.map(|days| days as i32)),
+        task_template::position::set(position),
+    ]
+}
+
+fn start_date(start_date: Option<u64>) -> Result<DateTime<Utc>, String> {
+    match start_date {
+        Some(start_date) => DateTime::from_timestamp(start_date as i64, 0).ok_or_else(|| "Invalid start date".to_string()),
+        None => Ok(Utc::now()),
+    }
+}
+
+/// The task a template creates, its roles resolved to users. Roles nobody fills are left unassigned.
+fn task_from_template(
+    template: &task_template::Data,
+    project_id: u64,
+    users: &HashMap<String, u64>,
+    start: DateTime<Utc>,
+) -> CreateTaskRequest {
+    let mut attached_to: Vec<u64> = template.assignee_roles.iter().filter_map(|role| users.get(role).copied()).collect();
+    attached_to.sort_unstable();
+    attached_to.dedup();
+    CreateTaskRequest {
+        name: template.name.clone(),
+        description: template.description.clone(),
+        project_id,
+        attached_to,
+        due_date: template.due_offset_days.map(|days| (start + Duration::days(days as i64)).timestamp() as u64),
+        assigned_issue: None,
+        parent_id: None,
+        priority: TaskPriority::from_str(&template.priority).ok(),
+        estimate: template.estimate,
+        label_ids: vec![],
+    }
+}
+
+pub async fn get_task_templates(user_id: u64) -> Result<Vec<SelectTaskTemplate>, String> {
+    let client = create_prisma_client().await?;
+    let templates = client
+        .task_template()
+        .find_many(vec![
+            task_template::owner_id::equals(user_id as i32),
+            task_template::project_template_id::equals(None),
+        ])
+        .order_by(task_template::name::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get task templates: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(templates.iter().map(task_template_to_response).collect())
+}
+
+pub async fn create_task_template(user_id: u64, request: &TaskTemplateRequest) -> Result<SelectTaskTemplate, String> {
+    let client = create_prisma_client().await?;
+    let template = client
+        .task_template()
+        .create(
+            user::id::equals(user_id as i32),
+            request.name.clone(),
+            request.description.clone(),
+            task_template_params(request, 0),
+        )
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to create task template: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(task_template_to_response(&template))
+}
+
+pub async fn delete_task_template(user_id: u64, template_id: u64) -> Result<Option<()>, String> {
+    let client = create_prisma_client().await?;
+    let deleted = client
+        .task_template()
+        .delete_many(vec![
+            task_template::id::equals(template_id as i32),
+            task_template::owner_id::equals(user_id as i32),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok((deleted > 0).then_some(()))
+}
+
+/// Creates a task in a project from one of the user's task templates,
+/// including those that are part of a project template.
+pub async fn create_task_from_template(
+    user_id: u64,
+    template_id: u64,
+    request: &CreateTaskFromTemplateRequest,
+) -> Result<Option<SelectTask>, String> {
+    require_project_participant(user_id, request.project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let template = match client
+        .task_template()
+        .find_first(vec![
+            task_template::id::equals(template_id as i32),
+            task_template::owner_id::equals(user_id as i32),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(template) => template,
+        None => return Ok(None),
+    };
+
+    for (role, assignee_id) in &request.assignees {
+        let participant = is_project_member(*assignee_id, request.project_id).await?
+            || is_project_owner(*assignee_id, request.project_id).await?;
+        if !participant {
+            return Err(format!("User {} for role {} is not a member of the project", assignee_id, role));
+        }
+    }
+
+    let task = task_from_template(&template, request.project_id, &request.assignees, start_date(request.start_date)?);
+    Ok(Some(create_task(Actor::User(user_id), &task).await?))
+}
+
+async fn find_project_template(user_id: u64, template_id: u64) -> Result<Option<project_template::Data>, String> {
+    let client = create_prisma_client().await?;
+    client
+        .project_template()
+        .find_first(vec![
+            project_template::id::equals(template_id as i32),
+            project_template::owner_id::equals(user_id as i32),
+        ])
+        .with(project_template::roles::fetch(vec![]).with(template_role::default_user::fetch()))
+        .with(project_template::tasks::fetch(vec![]).order_by(task_template::position::order(Direction::Asc)))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project template: {:?}", err);
+            err.to_string()
+        })
+}
+
+pub async fn get_project_templates(user_id: u64) -> Result<Vec<SelectProjectTemplate>, String> {
+    let client = create_prisma_client().await?;
+    let templates = client
+        .project_template()
+        .find_many(vec![project_template::owner_id::equals(user_id as i32)])
+        .with(project_template::roles::fetch(vec![]).with(template_role::default_user::fetch()))
+        .with(project_template::tasks::fetch(vec![]).order_by(task_template::position::order(Direction::Asc)))
+        .order_by(project_template::name::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project templates: {:?}", err);
+            err.to_string()
+        })?;
+    templates.iter().map(project_template_to_response).collect()
+}
+
+pub async fn get_project_template(user_id: u64, template_id: u64) -> Result<Option<SelectProjectTemplate>, String> {
+    match find_project_template(user_id, template_id).await? {
+        Some(template) => Ok(Some(project_template_to_response(&template)?)),
+        None => Ok(None),
+    }
+}
+
+/// Stores a project template with its roles and task templates in one transaction.
+async fn store_project_template(user_id: u64, request: CreateProjectTemplateRequest) -> Result<SelectProjectTemplate, String> {
+    for task in &request.tasks {
+        if let Some(role) = task.assignee_roles.iter().find(|role| !request.roles.iter().any(|defined| defined.name == **role)) {
+            return Err(format!("Task template {} refers to an unknown role {}", task.name, role));
+        }
+    }
+
+    let client = create_prisma_client().await?;
+    let template_id = client
+        ._transaction()
+        .run(|tx| async move {
+            let template = tx
+                .project_template()
+                .create(user::id::equals(user_id as i32), request.name.clone(), request.description.clone(), vec![])
+                .exec()
+                .await?;
+            for role in &request.roles {
+                tx.template_role()
+                    .create(
+                        project_template::id::equals(template.id),
+                        role.name.clone(),
+                        vec![template_role::default_user_id::set(role.default_user_id.map(|id| id as i32))],
+                    )
+                    .exec()
+                    .await?;
+            }
+            for (position, task) in request.tasks.iter().enumerate() {
+                let mut params = task_template_params(task, position as i32);
+                params.push(task_template::project_template::connect(project_template::id::equals(template.id)));
+                tx.task_template()
+                    .create(user::id::equals(user_id as i32), task.name.clone(), task.description.clone(), params)
+                    .exec()
+                    .await?;
+            }
+            Ok(template.id)
+        })
+        .await
+        .map_err(|err: QueryError| {
+            log::error!(target: LOG_TAG, "Failed to create project template: {:?}", err);
+            err.to_string()
+        })?;
+
+    get_project_template(user_id, template_id as u64).await?
+        .ok_or_else(|| "Project template not found".to_string())
+}
+
+pub async fn create_project_template(user_id: u64, request: CreateProjectTemplateRequest) -> Result<SelectProjectTemplate, String> {
+    let mut role_names: Vec<&str> = request.roles.iter().map(|role| role.name.as_str()).collect();
+    role_names.sort_unstable();
+    role_names.dedup();
+    if role_names.len() != request.roles.len() {
+        return Err("Role names must be unique".to_string());
+    }
+    store_project_template(user_id, request).await
+}
+
+/// Saves the tasks of a project as a project template. Due offsets count from the project's creation,
+/// and with `include_members` every participant becomes a role filled with them by default.
+pub async fn save_project_as_template(
+    user_id: u64,
+    project_id: u64,
+    request: &SaveProjectTemplateRequest,
+) -> Result<SelectProjectTemplate, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let project = client
+        .project()
+        .find_unique(project::id::equals(project_id as i32))
+        .with(project::owner::fetch())
+        .with(project::members::fetch(vec![]))
+        .with(
+            project::tasks::fetch(vec![])
+                .with(task::attached_to::fetch(vec![]))
+                .order_by(task::id::order(Direction::Asc)),
+        )
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Project not found".to_string())?;
+
+    let include_members = request.include_members.unwrap_or(false);
+    let mut roles = vec![];
+    if include_members {
+        let owner = project.owner.as_deref().ok_or_else(|| "Failed to fetch project owner".to_string())?;
+        for participant in std::iter::once(owner).chain(project.members.iter().flatten()) {
+            if !roles.iter().any(|role: &TemplateRoleRequest| role.name == participant.username) {
+                roles.push(TemplateRoleRequest {
+                    name: participant.username.clone(),
+                    default_user_id: Some(participant.id as u64),
+                });
+            }
+        }
+    }
+
+    let tasks = project.tasks.iter().flatten()
+        .map(|task| TaskTemplateRequest {
+            name: task.name.clone(),
+            description: task.description.clone(),
+            priority: TaskPriority::from_str(&task.priority).ok(),
+            estimate: task.estimate,
+            assignee_roles: task.attached_to.iter().flatten()
+                .map(|user| user.username.clone())
+                .filter(|username| roles.iter().any(|role| role.name == *username))
+                .collect(),
+            due_offset_days: task.due_date.map(|due_date| (due_date - project.created_at).num_days().max(0) as u64),
+        })
+        .collect();
+
+    store_project_template(
+        user_id,
+        CreateProjectTemplateRequest {
+            name: request.name.clone(),
+            description: request.description.clone(),
+            roles,
+            tasks,
+        },
+    )
+    .await
+}
+
+pub async fn delete_project_template(user_id: u64, template_id: u64) -> Result<Option<()>, String> {
+    let client = create_prisma_client().await?;
+    let deleted = client
+        .project_template()
+        .delete_many(vec![
+            project_template::id::equals(template_id as i32),
+            project_template::owner_id::equals(user_id as i32),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok((deleted > 0).then_some(()))
+}
+
+/// Creates a project owned by the user with the tasks of a template. Users filling the roles
+/// become members of the project and are assigned to the tasks of their roles.
+pub async fn create_project_from_template(
+    user_id: u64,
+    template_id: u64,
+    request: &CreateProjectFromTemplateRequest,
+) -> Result<Option<SelectProject>, String> {
+    let template = match find_project_template(user_id, template_id).await? {
+        Some(template) => template,
+        None => return Ok(None),
+    };
+    if let Some(role) = request.assignees.keys().find(|role| !template.roles.iter().flatten().any(|defined| defined.name == **role)) {
+        return Err(format!("Template has no role {}", role));
+    }
+
+    let mut users: HashMap<String, u64> = template.roles.iter().flatten()
+        .filter_map(|role| role.default_user_id.map(|id| (role.name.clone(), id as u64)))
+        .collect();
+    users.extend(request.assignees.clone());
+
+    let mut members: Vec<u64> = users.values().copied().filter(|id| *id != user_id).collect();
+    members.sort_unstable();
+    members.dedup();
+    let project = create_project(
+        user_id,
+        &CreateProjectRequest {
+            name: request.name.clone(),
+            description: request.description.clone(),
+            members,
+        },
+    )
+    .await?;
+
+    let start = start_date(request.start_date)?;
+    for task_template in template.tasks.iter().flatten() {
+        let task = task_from_template(task_template, project.id, &users, start);
+        if let Err(err) = create_task(Actor::User(user_id), &task).await {
+            // A half-filled project is worse than none
+            delete_project(user_id, project.id).await.ok();
+            return Err(err);
+        }
+    }
+
+    get_project_by_id(user_id, project.id).await
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use prisma_client_rust::Direction;
+use prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation;
+
+use crate::models::time_entry::{
+    CreateWorklogRequest, SelectTimeEntry, SelectTimeReport, SelectTimeReportRow, TimeReportQuery,
+};
+use crate::prisma::{task, time_entry, user};
+use crate::services::common::create_prisma_client;
+use crate::services::task::{check_member_from_task, require_project_participant};
+use crate::services::user::user_data_to_response;
+
+const LOG_TAG: &'static str = "TimeEntryService";
+
+/// Seconds an entry covers within `[from, to)`, a running entry lasting until `to`.
+fn entry_seconds(entry: &time_entry::Data, from: Option<DateTime<FixedOffset>>, to: DateTime<FixedOffset>) -> u64 {
+    let started_at = from.map_or(entry.started_at, |from| entry.started_at.max(from));
+    let ended_at = entry.ended_at.map_or(to, |ended_at| ended_at.min(to));
+    (ended_at - started_at).num_seconds().max(0) as u64
+}
+
+/// Seconds logged in the given entries, running timers counted up to now.
+pub fn total_time_spent(entries: &[time_entry::Data]) -> u64 {
+    let now = Utc::now().into();
+    entries.iter().map(|entry| entry_seconds(entry, None, now)).sum()
+}
+
+pub fn time_entry_to_response(entry: &time_entry::Data) -> Result<SelectTimeEntry, String> {
+    let user = match &entry.user {
+        Some(user) => user_data_to_response(user),
+        None => return Err("Failed to fetch time entry user".to_string()),
+    };
+    Ok(SelectTimeEntry {
+        id: entry.id as u64,
+        task_id: entry.task_id as u64,
+        user,
+        started_at: entry.started_at.timestamp() as u64,
+        ended_at: entry.ended_at.map(|date| date.timestamp() as u64),
+        duration: entry_seconds(entry, None, Utc::now().into()),
+        note: entry.note.clone(),
+    })
+}
+
+pub async fn get_task_time_entries(user_id: u64, task_id: u64) -> Result<Vec<SelectTimeEntry>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let entries = client
+        .time_entry()
+        .find_many(vec![time_entry::task_id::equals(task_id as i32)])
+        .with(time_entry::user::fetch())
+        .order_by(time_entry::started_at::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get time entries: {:?}", err);
+            err.to_string()
+        })?;
+    entries.iter().map(time_entry_to_response).collect()
+}
+
+/// Starts a timer of the user on a task. A user has at most one running timer.
+pub async fn start_timer(user_id: u64, task_id: u64) -> Result<SelectTimeEntry, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let running = client
+        .time_entry()
+        .find_first(vec![
+            time_entry::user_id::equals(user_id as i32),
+            time_entry::ended_at::equals(None),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    if let Some(running) = running {
+        return Err(format!("A timer is already running on task {}", running.task_id));
+    }
+
+    let entry = client
+        .time_entry()
+        .create(
+            task::id::equals(task_id as i32),
+            user::id::equals(user_id as i32),
+            Utc::now().into(),
+            vec![],
+        )
+        .with(time_entry::user::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            // Another request started a timer in the meantime
+            if err.is_prisma_error::<UniqueKeyViolation>() {
+                return "A timer is already running".to_string();
+            }
+            log::error!(target: LOG_TAG, "Failed to start timer: {:?}", err);
+            err.to_string()
+        })?;
+    time_entry_to_response(&entry)
+}
+
+/// Stops the running timer of the user on a task.
+pub async fn stop_timer(user_id: u64, task_id: u64) -> Result<Option<SelectTimeEntry>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let running = match client
+        .time_entry()
+        .find_first(vec![
+            time_entry::user_id::equals(user_id as i32),
+            time_entry::task_id::equals(task_id as i32),
+            time_entry::ended_at::equals(None),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(entry) => entry,
+        None => return Ok(None),
+    };
+
+    let entry = client
+        .time_entry()
+        .update(
+            time_entry::id::equals(running.id),
+            vec![time_entry::ended_at::set(Some(Utc::now().into()))],
+        )
+        .with(time_entry::user::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to stop timer: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(Some(time_entry_to_response(&entry)?))
+}
+
+/// Logs time spent on a task after the fact.
+pub async fn create_worklog(user_id: u64, task_id: u64, request: &CreateWorklogRequest) -> Result<SelectTimeEntry, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let started_at = DateTime::from_timestamp(request.started_at as i64, 0)
+        .ok_or_else(|| "Invalid start time".to_string())?;
+    let ended_at = started_at + Duration::minutes(request.duration_minutes as i64);
+    if ended_at > Utc::now() {
+        return Err("A worklog can't end in the future".to_string());
+    }
+
+    let client = create_prisma_client().await?;
+    let entry = client
+        .time_entry()
+        .create(
+            task::id::equals(task_id as i32),
+            user::id::equals(user_id as i32),
+            started_at.into(),
+            vec![
+                time_entry::ended_at::set(Some(ended_at.into())),
+                time_entry::note::set(request.note.clone()),
+            ],
+        )
+        .with(time_entry::user::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to create worklog: {:?}", err);
+            err.to_string()
+        })?;
+    time_entry_to_response(&entry)
+}
+
+/// Deletes a time entry of the user.
+pub async fn delete_time_entry(user_id: u64, task_id: u64, entry_id: u64) -> Result<Option<()>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let entry = match client
+        .time_entry()
+        .find_unique(time_entry::id::equals(entry_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(entry) if entry.task_id as u64 == task_id => entry,
+        _ => return Ok(None),
+    };
+    if entry.user_id as u64 != user_id {
+        return Err("Only the author can delete the time entry".to_string());
+    }
+
+    client
+        .time_entry()
+        .delete(time_entry::id::equals(entry.id))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to delete time entry: {:?}", err);
+            err.to_string()
+        })?;
+    Ok(Some(()))
+}
+
+pub async fn get_time_report(user_id: u64, project_id: u64, query: &TimeReportQuery) -> Result<SelectTimeReport, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let from: Option<DateTime<FixedOffset>> = match query.from {
+        Some(from) => Some(DateTime::from_timestamp(from as i64, 0).ok_or_else(|| "Invalid start of the period".to_string())?.into()),
+        None => None,
+    };
+    let to: DateTime<FixedOffset> = match query.to {
+        Some(to) => DateTime::from_timestamp(to as i64, 0).ok_or_else(|| "Invalid end of the period".to_string())?.into(),
+        None => Utc::now().into(),
+    };
+
+    let mut filters = vec![
+        time_entry::task::is(vec![task::project_id::equals(project_id as i32)]),
+        time_entry::started_at::lt(to),
+    ];
+    if let Some(from) = from {
+        // Entries still running overlap any period that started before now
+        filters.push(prisma_client_rust::or![
+            time_entry::ended_at::gt(from),
+            time_entry::ended_at::equals(None)
+        ]);
+    }
+    if let Some(report_user_id) = query.user_id {
+        filters.push(time_entry::user_id::equals(report_user_id as i32));
+    }
+
+    let client = create_prisma_client().await?;
+    let entries = client
+        .time_entry()
+        .find_many(filters)
+        .with(time_entry::user::fetch())
+        .with(time_entry::task::fetch())
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get time report entries: {:?}", err);
+            err.to_string()
+        })?;
+
+    let mut rows: BTreeMap<(i32, i32), SelectTimeReportRow> = BTreeMap::new();
+    for entry in &entries {
+        let (Some(entry_user), Some(entry_task)) = (&entry.user, &entry.task) else {
+            return Err("Failed to fetch time entry user or task".to_string());
+        };
+        let seconds = entry_seconds(entry, from, to);
+        rows.entry((entry.user_id, entry.task_id))
+            .or_insert_with(|| SelectTimeReportRow {
+                user: user_data_to_response(entry_user),
+                task_id: entry_task.id as u64,
+                task_name: entry_task.name.clone(),
+                seconds: 0,
+            })
+            .seconds += seconds;
+    }
+
+    let rows: Vec<SelectTimeReportRow> = rows.into_values().filter(|row| row.seconds > 0).collect();
+    Ok(SelectTimeReport {
+        project_id,
+        from: from.map(|date| date.timestamp() as u64),
+        to: to.timestamp() as u64,
+        total_seconds: rows.iter().map(|row| row.seconds).sum(),
+        rows,
+    })
+}
+
+fn csv_field(value: &str) -> String {
+    // Spreadsheets run cells starting with these as formulas
+    let value = if value.starts_with(|c| matches!(c, '=' | '+' | '-' | '@' | '\t' | '\r')) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Renders a time report as CSV with one line per user and task, hours rounded to hundredths.
+pub fn time_report_to_csv(report: &SelectTimeReport) -> String {
+    let mut csv = String::from("user_id,username,name,task_id,task,hours\n");
+    for row in &report.rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.2}\n",
+            row.user.id,
+            csv_field(&row.user.username),
+            csv_field(&format!("{} {}", row.user.first_name, row.user.last_name)),
+            row.task_id,
+            csv_field(&row.task_name),
+            row.seconds as f64 / 3600.0
+        ));
+    }
+    csv.push_str(&format!(",,,,Total,{:.2}\n", report.total_seconds as f64 / 3600.0));
+    csv
+}
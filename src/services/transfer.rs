@@ -0,0 +1,301 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use prisma_client_rust::{and, or, Direction, QueryError};
+use prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation;
+
+use crate::mailer::mailer::Mailer;
+use crate::models::actor::Actor;
+use crate::models::task::{CreateTaskRequest, SelectTask, TaskStatus};
+use crate::models::transfer::{DuplicateTaskRequest, MoveTaskToProjectRequest, SelectMovedTask};
+use crate::prisma::{label, project, project_status, task, task_dependency, user};
+use crate::services::board::next_board_position;
+use crate::services::common::create_prisma_client;
+use crate::services::dependency::propagate_status_change;
+use crate::services::history::record_task_changes;
+use crate::services::task::{
+    check_member_from_task, collect_descendants, create_task, get_task_by_id, next_position, require_project_participant,
+    updated_by_params,
+};
+use crate::services::user::user_data_to_response;
+use crate::services::workflow::{first_status_in_category, get_project_statuses, initial_status, status_category};
+
+const LOG_TAG: &'static str = "TransferService";
+
+/// Status a task takes in the target project: the same key when the target defines it,
+/// otherwise the first status of the same category. Blocked tasks are unblocked first,
+/// their links to tasks left behind are dropped by the move.
+fn target_status(task: &task::Data, source: &[project_status::Data], target: &[project_status::Data]) -> String {
+    let status = match &task.status_before_block {
+        Some(previous_status) if task.status == TaskStatus::Blocked.to_string() => previous_status,
+        _ => &task.status,
+    };
+    if target.iter().any(|candidate| candidate.key == *status) {
+        return status.clone();
+    }
+    first_status_in_category(target, status_category(source, status)).unwrap_or_else(|| initial_status(target))
+}
+
+/// Moves a task and all of its subtasks to another project. The task leaves its parent, labels are
+/// matched by name, dependencies on tasks left behind and the recurring series are dropped.
+pub async fn move_task_to_project(
+    mailer: &Mailer,
+    user_id: u64,
+    task_id: u64,
+    request: &MoveTaskToProjectRequest,
+) -> Result<Option<SelectMovedTask>, String> {
+    check_member_from_task(user_id, task_id).await?;
+    require_project_participant(user_id, request.project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let root = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(root) => root,
+        None => return Ok(None),
+    };
+    let source_project_id = root.project_id as u64;
+    if source_project_id == request.project_id {
+        return Err("Task already belongs to the project".to_string());
+    }
+
+    let mut moved_ids = vec![root.id];
+    moved_ids.extend(
+        collect_descendants(&client, task_id).await
+            .map_err(|err| err.to_string())?
+            .iter()
+            .map(|subtask| subtask.id),
+    );
+    let moved: Vec<task::Data> = client
+        .task()
+        .find_many(vec![task::id::in_vec(moved_ids.clone())])
+        .with(task::attached_to::fetch(vec![]))
+        .with(task::labels::fetch(vec![]))
+        .order_by(task::id::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let target = client
+        .project()
+        .find_unique(project::id::equals(request.project_id as i32))
+        .with(project::members::fetch(vec![]))
+        .with(project::labels::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Project not found".to_string())?;
+    let mut participants: HashSet<i32> = target.members.iter().flatten().map(|member| member.id).collect();
+    participants.insert(target.owner_id);
+
+    let mut removed_assignees: Vec<user::Data> = vec![];
+    for assignee in moved.iter().flat_map(|task| task.attached_to.iter().flatten()) {
+        if !participants.contains(&assignee.id) && !removed_assignees.iter().any(|removed| removed.id == assignee.id) {
+            removed_assignees.push(assignee.clone());
+        }
+    }
+    if !removed_assignees.is_empty() && !request.remove_non_members.unwrap_or(false) {
+        let usernames: Vec<String> = removed_assignees.iter().map(|user| user.username.clone()).collect();
+        return Err(format!("Assignees are not members of the target project: {}", usernames.join(", ")));
+    }
+
+    let keep_issue = request.keep_issue.unwrap_or(false);
+    if keep_issue {
+        // An issue number only means the same issue within the same repository
+        let source = client
+            .project()
+            .find_unique(project::id::equals(source_project_id as i32))
+            .exec()
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| "Project not found".to_string())?;
+        let same_repository = source.repo_id.is_some()
+            && source.repo_id == target.repo_id
+            && source.repo_provider == target.repo_provider
+            && source.repo_base_url == target.repo_base_url;
+        if !same_repository {
+            return Err("The linked issue can only be kept when both projects use the same repository".to_string());
+        }
+    }
+    let target_labels: HashMap<String, i32> = target.labels.iter().flatten()
+        .map(|label| (label.name.clone(), label.id))
+        .collect();
+    let source_statuses = get_project_statuses(source_project_id).await?;
+    let target_statuses = get_project_statuses(request.project_id).await?;
+
+    // Tasks left behind that lose a blocker may be unblocked afterwards
+    let blocked_left_behind: Vec<u64> = client
+        .task_dependency()
+        .find_many(vec![
+            task_dependency::blocker_id::in_vec(moved_ids.clone()),
+            task_dependency::blocked_id::not_in_vec(moved_ids.clone()),
+        ])
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .iter()
+        .map(|link| link.blocked_id as u64)
+        .collect();
+
+    let project_id = request.project_id;
+    let actor = Actor::User(user_id);
+    let befores = moved.clone();
+    let changes = client
+        ._transaction()
+        .run(|tx| async move {
+            tx.task_dependency()
+                .delete_many(vec![or![
+                    and![
+                        task_dependency::blocker_id::in_vec(moved_ids.clone()),
+                        task_dependency::blocked_id::not_in_vec(moved_ids.clone())
+                    ],
+                    and![
+                        task_dependency::blocked_id::in_vec(moved_ids.clone()),
+                        task_dependency::blocker_id::not_in_vec(moved_ids.clone())
+                    ]
+                ]])
+                .exec()
+                .await?;
+
+            let mut changes = vec![];
+            for task in befores {
+                let status = target_status(&task, &source_statuses, &target_statuses);
+                let assignee_ids: Vec<i32> = task.attached_to.iter().flatten()
+                    .map(|user| user.id)
+                    .filter(|id| participants.contains(id))
+                    .collect();
+                let label_ids: Vec<i32> = task.labels.iter().flatten()
+                    .filter_map(|label| target_labels.get(&label.name).copied())
+                    .collect();
+
+                let mut update_properties = updated_by_params(&Actor::User(user_id));
+                update_properties.extend(vec![
+                    task::project::connect(project::id::equals(project_id as i32)),
+                    task::board_position::set(next_board_position(&tx, project_id, &status).await?),
+                    task::status::set(status),
+                    task::status_before_block::set(None),
+                    task::attached_to::set(assignee_ids.into_iter().map(user::id::equals).collect()),
+                    task::labels::set(label_ids.into_iter().map(label::id::equals).collect()),
+                    task::recurrence::disconnect(),
+                    task::next_created::set(false),
+                ]);
+                if !keep_issue {
+                    update_properties.push(task::assigned_issue::set(None));
+                }
+                if task.id == task_id as i32 {
+                    update_properties.push(task::parent::disconnect());
+                    update_properties.push(task::position::set(next_position(&tx, project_id, None).await?));
+                }
+
+                let after = tx
+                    .task()
+                    .update(task::id::equals(task.id), update_properties)
+                    .with(task::attached_to::fetch(vec![]))
+                    .with(task::labels::fetch(vec![]))
+                    .exec()
+                    .await?;
+                changes.push((task, after));
+            }
+            Ok(changes)
+        })
+        .await
+        .map_err(|err: QueryError| {
+            if err.is_prisma_error::<UniqueKeyViolation>() {
+                return "Another task of the target project is already linked to the same issue".to_string();
+            }
+            log::error!(target: LOG_TAG, "Failed to move task {task_id}: {:?}", err);
+            err.to_string()
+        })?;
+
+    for (before, after) in &changes {
+        record_task_changes(&actor, before, after).await;
+    }
+    // Moved tasks may still be blocked by each other
+    let moved_task_ids: Vec<u64> = changes.iter().map(|(_, after)| after.id as u64).collect();
+    for moved_id in &moved_task_ids {
+        propagate_status_change(mailer, &actor, *moved_id, &[]).await;
+    }
+    for blocked_id in blocked_left_behind {
+        propagate_status_change(mailer, &actor, blocked_id, &[]).await;
+    }
+
+    let task = get_task_by_id(task_id).await?.ok_or_else(|| "Task not found".to_string())?;
+    Ok(Some(SelectMovedTask {
+        task,
+        moved_task_ids,
+        removed_assignees: removed_assignees.iter().map(user_data_to_response).collect(),
+    }))
+}
+
+/// Copies a task, and with `include_subtasks` everything below it, into the same place of its project.
+/// Comments, attachments, time entries, dependencies and the linked issue are not copied.
+pub async fn duplicate_task(user_id: u64, task_id: u64, request: &DuplicateTaskRequest) -> Result<Option<SelectTask>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let include_assignees = request.include_assignees.unwrap_or(false);
+    let include_description = request.include_description.unwrap_or(true);
+    let include_subtasks = request.include_subtasks.unwrap_or(false);
+
+    let mut created_ids: Vec<i32> = vec![];
+    let mut queue: VecDeque<(i32, Option<u64>)> = VecDeque::from([(task_id as i32, None)]);
+    while let Some((source_id, copied_parent_id)) = queue.pop_front() {
+        let source = match client
+            .task()
+            .find_unique(task::id::equals(source_id))
+            .with(task::attached_to::fetch(vec![]))
+            .with(task::labels::fetch(vec![]))
+            .with(task::children::fetch(vec![]).order_by(task::position::order(Direction::Asc)))
+            .exec()
+            .await
+            .map_err(|err| err.to_string())?
+        {
+            Some(source) => source,
+            None if created_ids.is_empty() => return Ok(None),
+            // A subtask deleted meanwhile is just not copied
+            None => continue,
+        };
+        let is_root = copied_parent_id.is_none();
+
+        let copy = CreateTaskRequest {
+            name: match &request.name {
+                Some(name) if is_root => name.clone(),
+                _ => source.name.clone(),
+            },
+            description: if include_description { source.description.clone() } else { String::new() },
+            project_id: source.project_id as u64,
+            attached_to: if include_assignees {
+                source.attached_to.iter().flatten().map(|user| user.id as u64).collect()
+            } else {
+                vec![]
+            },
+            due_date: source.due_date.map(|date| date.timestamp() as u64),
+            assigned_issue: None,
+            parent_id: if is_root { source.parent_id.map(|id| id as u64) } else { copied_parent_id },
+            priority: source.priority.parse().ok(),
+            estimate: source.estimate,
+            label_ids: source.labels.iter().flatten().map(|label| label.id as u64).collect(),
+        };
+        let created = match create_task(Actor::User(user_id), &copy).await {
+            Ok(created) => created,
+            Err(err) => {
+                // Copies made so far are removed, a partial duplicate is of no use
+                client.task().delete_many(vec![task::id::in_vec(created_ids)]).exec().await.ok();
+                log::error!(target: LOG_TAG, "Failed to duplicate task {task_id}: {err}");
+                return Err(err);
+            }
+        };
+        created_ids.push(created.id as i32);
+
+        if include_subtasks {
+            for child in source.children.iter().flatten() {
+                queue.push_back((child.id, Some(created.id)));
+            }
+        }
+    }
+
+    get_task_by_id(created_ids[0] as u64).await
+}
@@ -1,6 +1,8 @@
+use std::str::FromStr;
+
 use prisma_client_rust::or;
 
-use crate::models::{auth::RegisterRequest, user::SelectUser};
+use crate::models::{actor::{SelectActor, SystemActor}, auth::RegisterRequest, user::SelectUser};
 use crate::models::user::SelectUserQuery;
 use crate::prisma::{project, QueryMode, user};
 use crate::services::common::create_prisma_client;
@@ -19,6 +21,23 @@ pub fn user_data_to_response(user: &user::Data) -> SelectUser {
     }
 }
 
+/// Converts the user or system actor recorded on an entity into a response model.
+pub fn actor_to_response(user: Option<&user::Data>, system: Option<&str>) -> Option<SelectActor> {
+    if let Some(user) = user {
+        return Some(SelectActor {
+            user: Some(user_data_to_response(user)),
+            system: None,
+            display_name: format!("{} {}", user.first_name, user.last_name),
+        });
+    }
+    let system = SystemActor::from_str(system?).ok()?;
+    Some(SelectActor {
+        user: None,
+        system: Some(system),
+        display_name: system.display_name().to_string(),
+    })
+}
+
 pub async fn get_user_id_by_credentials(
     username: &str,
     password: &str,
@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::DateTime;
+
+use crate::mailer::mailer::Mailer;
+use crate::models::actor::Actor;
+use crate::models::task::SelectTask;
+use crate::models::user::SelectUser;
+use crate::prisma::{project, task, user};
+use crate::services::common::create_prisma_client;
+use crate::services::notifications::create_notification;
+use crate::services::task::{check_member_from_task, require_project_participant};
+use crate::services::user::user_data_to_response;
+
+const LOG_TAG: &'static str = "WatcherService";
+
+pub async fn get_task_watchers(user_id: u64, task_id: u64) -> Result<Vec<SelectUser>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    let task = client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::watchers::fetch(vec![]))
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+    Ok(task.watchers.unwrap_or_default().iter().map(user_data_to_response).collect())
+}
+
+/// Adds users to the watchers of a task. Creators, assignees and commenters are added automatically.
+pub async fn add_task_watchers(task_id: u64, user_ids: &[u64]) -> Result<(), String> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+    let client = create_prisma_client().await?;
+    client
+        .task()
+        .update(
+            task::id::equals(task_id as i32),
+            vec![task::watchers::connect(user_ids.iter().map(|id| user::id::equals(*id as i32)).collect())],
+        )
+        .exec()
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to add watchers of task {task_id}: {:?}", err);
+            err.to_string()
+        })
+}
+
+pub async fn watch_task(user_id: u64, task_id: u64) -> Result<Vec<SelectUser>, String> {
+    check_member_from_task(user_id, task_id).await?;
+    add_task_watchers(task_id, &[user_id]).await?;
+    get_task_watchers(user_id, task_id).await
+}
+
+pub async fn unwatch_task(user_id: u64, task_id: u64) -> Result<Vec<SelectUser>, String> {
+    check_member_from_task(user_id, task_id).await?;
+
+    let client = create_prisma_client().await?;
+    client
+        .task()
+        .update(
+            task::id::equals(task_id as i32),
+            vec![task::watchers::disconnect(vec![user::id::equals(user_id as i32)])],
+        )
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?;
+    get_task_watchers(user_id, task_id).await
+}
+
+/// Watching a project means watching every task in it.
+pub async fn set_project_watching(user_id: u64, project_id: u64, watching: bool) -> Result<(), String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let client = create_prisma_client().await?;
+    let watcher = vec![user::id::equals(user_id as i32)];
+    client
+        .project()
+        .update(
+            project::id::equals(project_id as i32),
+            vec![match watching {
+                true => project::watchers::connect(watcher),
+                false => project::watchers::disconnect(watcher),
+            }],
+        )
+        .exec()
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to update watchers of project {project_id}: {:?}", err);
+            err.to_string()
+        })
+}
+
+/// Users watching a task directly or through its project. Users who left the project are skipped.
+pub async fn get_watcher_ids(task_id: u64) -> Result<BTreeSet<u64>, String> {
+    let client = create_prisma_client().await?;
+    let task = match client
+        .task()
+        .find_unique(task::id::equals(task_id as i32))
+        .with(task::watchers::fetch(vec![]))
+        .with(
+            task::project::fetch()
+                .with(project::members::fetch(vec![]))
+                .with(project::watchers::fetch(vec![])),
+        )
+        .exec()
+        .await
+        .map_err(|err| err.to_string())?
+    {
+        Some(task) => task,
+        None => return Ok(BTreeSet::new()),
+    };
+    let project = task.project.as_deref().ok_or_else(|| "Failed to fetch task project".to_string())?;
+
+    let mut participants: BTreeSet<u64> = project.members.iter().flatten().map(|member| member.id as u64).collect();
+    participants.insert(project.owner_id as u64);
+    Ok(task.watchers.iter().flatten()
+        .chain(project.watchers.iter().flatten())
+        .map(|watcher| watcher.id as u64)
+        .filter(|id| participants.contains(id))
+        .collect())
+}
+
+/// Watcher notifications gathered over several task changes, sent as one notification per recipient.
+#[derive(Default)]
+pub struct WatcherDigest {
+    notices: BTreeMap<u64, Vec<(String, String)>>,
+}
+
+impl WatcherDigest {
+    /// Adds a notice for the watchers of a task, except the actor.
+    pub async fn add(&mut self, actor: &Actor, task_id: u64, title: &str, text: String) -> Result<(), String> {
+        self.add_for(actor, get_watcher_ids(task_id).await?, title, text);
+        Ok(())
+    }
+
+    /// Adds a notice for the given users, except the actor.
+    pub fn add_for(&mut self, actor: &Actor, recipient_ids: BTreeSet<u64>, title: &str, text: String) {
+        for recipient_id in recipient_ids {
+            if actor.user_id() == Some(recipient_id) {
+                continue;
+            }
+            self.notices.entry(recipient_id).or_default().push((title.to_string(), text.clone()));
+        }
+    }
+
+    pub async fn send(self, mailer: &Mailer) {
+        for (watcher_id, mut notices) in self.notices {
+            let (title, text) = match notices.len() {
+                1 => notices.remove(0),
+                _ => (
+                    "Изменения в отслеживаемых задачах".to_string(),
+                    notices.into_iter().map(|(_, text)| text).collect::<Vec<_>>().join(" "),
+                ),
+            };
+            create_notification(title, text, watcher_id, mailer).await;
+        }
+    }
+}
+
+/// Notifies the watchers of a task, except the actor, about a changed status, due date or description.
+pub async fn notify_task_watchers(mailer: &Mailer, actor: &Actor, before: &SelectTask, after: &SelectTask) {
+    let mut digest = WatcherDigest::default();
+    add_task_changes(&mut digest, actor, before, after).await;
+    digest.send(mailer).await;
+}
+
+/// Adds the changed status, due date or description of a task to a digest.
+pub async fn add_task_changes(digest: &mut WatcherDigest, actor: &Actor, before: &SelectTask, after: &SelectTask) {
+    let mut changes = vec![];
+    if before.status != after.status {
+        changes.push(format!("статус изменён на {}", after.status));
+    }
+    if before.due_date != after.due_date {
+        changes.push(match after.due_date.and_then(|due_date| DateTime::from_timestamp(due_date as i64, 0)) {
+            Some(due_date) => format!("срок изменён на {}", due_date.format("%d.%m.%Y")),
+            None => "срок снят".to_string(),
+        });
+    }
+    if before.description != after.description {
+        changes.push("описание обновлено".to_string());
+    }
+    if changes.is_empty() {
+        return;
+    }
+
+    let text = format!("В задаче {}: {}.", after.name, changes.join(", "));
+    if let Err(err) = digest.add(actor, after.id, "Изменения в отслеживаемой задаче", text).await {
+        log::error!(target: LOG_TAG, "Failed to get watchers of task {}: {}", after.id, err);
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use prisma_client_rust::{Direction, QueryError};
+
+use crate::models::task::TaskStatus;
+use crate::models::workflow::{
+    SelectProjectStatus, SelectStatusTransition, SelectWorkflow, StatusCategory, UpdateWorkflowRequest,
+};
+use crate::prisma::{project, project_status, status_transition, task, PrismaClient};
+use crate::services::common::create_prisma_client;
+use crate::services::task::require_project_participant;
+use crate::services::user::is_project_owner;
+
+const LOG_TAG: &'static str = "WorkflowService";
+
+pub fn project_status_to_response(status: &project_status::Data) -> SelectProjectStatus {
+    SelectProjectStatus {
+        id: status.id as u64,
+        key: status.key.clone(),
+        name: status.name.clone(),
+        category: category_from_str(&status.category),
+        position: status.position as u64,
+    }
+}
+
+fn category_from_str(category: &str) -> StatusCategory {
+    StatusCategory::from_str(category).unwrap_or(StatusCategory::Todo)
+}
+
+/// Category of a status key within a project. Keys the project doesn't define
+/// fall back to the default workflow and then to `Todo`, so a stray value never fails a request.
+pub fn status_category(statuses: &[project_status::Data], key: &str) -> StatusCategory {
+    statuses
+        .iter()
+        .find(|status| status.key == key)
+        .map(|status| category_from_str(&status.category))
+        .or_else(|| TaskStatus::from_str(key).ok().map(|status| status.category()))
+        .unwrap_or(StatusCategory::Todo)
+}
+
+/// The first status of a category in board order.
+pub fn first_status_in_category(statuses: &[project_status::Data], category: StatusCategory) -> Option<String> {
+    statuses
+        .iter()
+        .filter(|status| category_from_str(&status.category) == category)
+        .min_by_key(|status| status.position)
+        .map(|status| status.key.clone())
+}
+
+/// Status new tasks of a project start in.
+pub fn initial_status(statuses: &[project_status::Data]) -> String {
+    first_status_in_category(statuses, StatusCategory::Todo)
+        .or_else(|| statuses.iter().min_by_key(|status| status.position).map(|status| status.key.clone()))
+        .unwrap_or_else(|| TaskStatus::Todo.to_string())
+}
+
+pub async fn get_project_statuses(project_id: u64) -> Result<Vec<project_status::Data>, String> {
+    let client = create_prisma_client().await?;
+    client
+        .project_status()
+        .find_many(vec![project_status::project_id::equals(project_id as i32)])
+        .order_by(project_status::position::order(Direction::Asc))
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get project statuses: {:?}", err);
+            err.to_string()
+        })
+}
+
+async fn get_project_transitions(project_id: u64) -> Result<Vec<status_transition::Data>, String> {
+    let client = create_prisma_client().await?;
+    client
+        .status_transition()
+        .find_many(vec![status_transition::from::is(vec![project_status::project_id::equals(project_id as i32)])])
+        .exec()
+        .await
+        .map_err(|err| {
+            log::error!(target: LOG_TAG, "Failed to get status transitions: {:?}", err);
+            err.to_string()
+        })
+}
+
+/// Gives a new project the statuses of the default workflow.
+pub async fn create_default_workflow(client: &PrismaClient, project_id: u64) -> Result<(), QueryError> {
+    client
+        .project_status()
+        .create_many(
+            TaskStatus::DEFAULT_WORKFLOW
+                .iter()
+                .enumerate()
+                .map(|(position, status)| {
+                    project_status::create_unchecked(
+                        project_id as i32,
+                        status.to_string(),
+                        status.name().to_string(),
+                        status.category().to_string(),
+                        position as i32,
+                        vec![],
+                    )
+                })
+                .collect(),
+        )
+        .exec()
+        .await?;
+    Ok(())
+}
+
+/// Checks that a task of the project may move from one status to another.
+/// Any move is allowed while the project defines no transitions.
+pub async fn check_transition(project_id: u64, from: &str, to: &str) -> Result<(), String> {
+    let statuses = get_project_statuses(project_id).await?;
+    let target = statuses
+        .iter()
+        .find(|status| status.key == to)
+        .ok_or_else(|| format!("Project has no status {}", to))?;
+    if from == to {
+        return Ok(());
+    }
+
+    let transitions = get_project_transitions(project_id).await?;
+    if transitions.is_empty() {
+        return Ok(());
+    }
+    let allowed = statuses
+        .iter()
+        .find(|status| status.key == from)
+        .map_or(false, |source| {
+            transitions.iter().any(|transition| transition.from_id == source.id && transition.to_id == target.id)
+        });
+    if !allowed {
+        return Err(format!("Moving a task from {} to {} is not allowed by the project workflow", from, to));
+    }
+    Ok(())
+}
+
+pub async fn get_workflow(user_id: u64, project_id: u64) -> Result<SelectWorkflow, String> {
+    require_project_participant(user_id, project_id).await?;
+
+    let statuses = get_project_statuses(project_id).await?;
+    let keys: HashMap<i32, String> = statuses.iter().map(|status| (status.id, status.key.clone())).collect();
+    let transitions = get_project_transitions(project_id).await?
+        .into_iter()
+        .filter_map(|transition| {
+            Some(SelectStatusTransition {
+                from: keys.get(&transition.from_id)?.clone(),
+                to: keys.get(&transition.to_id)?.clone(),
+            })
+        })
+        .collect();
+
+    Ok(SelectWorkflow {
+        project_id,
+        statuses: statuses.iter().map(project_status_to_response).collect(),
+        transitions,
+    })
+}
+
+/// Replaces the statuses and transitions of a project. Statuses keep their identity by key,
+/// and a status can't be removed while tasks are in it.
+pub async fn update_workflow(user_id: u64, project_id: u64, request: &UpdateWorkflowRequest) -> Result<SelectWorkflow, String> {
+    if !is_project_owner(user_id, project_id).await? {
+        return Err("Only the project owner can change the workflow".to_string());
+    }
+
+    let keys: HashSet<&str> = request.statuses.iter().map(|status| status.key.as_str()).collect();
+    if keys.len() != request.statuses.len() {
+        return Err("Status keys must be unique".to_string());
+    }
+    for transition in &request.transitions {
+        if !keys.contains(transition.from.as_str()) || !keys.contains(transition.to.as_str()) {
+            return Err(format!("Transition {} -> {} refers to an unknown status", transition.from, transition.to));
+        }
+        if transition.from == transition.to {
+            return Err(format!("Transition {} -> {} leads to the same status", transition.from, transition.to));
+        }
+    }
+
+    let existing = get_project_statuses(project_id).await?;
+    let removed: Vec<String> = existing
+        .iter()
+        .map(|status| status.key.clone())
+        .filter(|key| !keys.contains(key.as_str()))
+        .collect();
+
+    let client = create_prisma_client().await?;
+    if !removed.is_empty() {
+        let in_use = client
+            .task()
+            .count(vec![
+                task::project_id::equals(project_id as i32),
+                task::status::in_vec(removed.clone()),
+            ])
+            .exec()
+            .await
+            .map_err(|err| err.to_string())?;
+        if in_use > 0 {
+            return Err(format!(
+                "{} tasks are still in the removed statuses {}. Move them first",
+                in_use,
+                removed.join(", ")
+            ));
+        }
+    }
+
+    let statuses: Vec<(String, String, String)> = request.statuses
+        .iter()
+        .map(|status| (status.key.clone(), status.name.clone(), status.category.to_string()))
+        .collect();
+    let transitions: Vec<(String, String)> = request.transitions
+        .iter()
+        .map(|transition| (transition.from.clone(), transition.to.clone()))
+        .collect();
+    client
+        ._transaction()
+        .run(|tx| async move {
+            tx.status_transition()
+                .delete_many(vec![status_transition::from::is(vec![project_status::project_id::equals(project_id as i32)])])
+                .exec()
+                .await?;
+            // A blocked task whose previous status is gone stays where it is once unblocked
+            tx.task()
+                .update_many(
+                    vec![
+                        task::project_id::equals(project_id as i32),
+                        task::status_before_block::in_vec(removed.clone()),
+                    ],
+                    vec![task::status_before_block::set(None)],
+                )
+                .exec()
+                .await?;
+            tx.project_status()
+                .delete_many(vec![
+                    project_status::project_id::equals(project_id as i32),
+                    project_status::key::in_vec(removed),
+                ])
+                .exec()
+                .await?;
+
+            let mut ids = HashMap::new();
+            for (position, (key, name, category)) in statuses.into_iter().enumerate() {
+                let status = tx
+                    .project_status()
+                    .upsert(
+                        project_status::project_id_key(project_id as i32, key.clone()),
+                        project_status::create(
+                            project::id::equals(project_id as i32),
+                            key.clone(),
+                            name.clone(),
+                            category.clone(),
+                            position as i32,
+                            vec![],
+                        ),
+                        vec![
+                            project_status::name::set(name),
+                            project_status::category::set(category),
+                            project_status::position::set(position as i32),
+                        ],
+                    )
+                    .exec()
+                    .await?;
+                ids.insert(key, status.id);
+            }
+
+            tx.status_transition()
+                .create_many(
+                    transitions
+                        .iter()
+                        .map(|(from, to)| status_transition::create_unchecked(ids[from], ids[to], vec![]))
+                        .collect(),
+                )
+                .exec()
+                .await
+        })
+        .await
+        .map_err(|err: QueryError| {
+            log::error!(target: LOG_TAG, "Failed to update workflow: {:?}", err);
+            err.to_string()
+        })?;
+
+    get_workflow(user_id, project_id).await
+}
@@ -0,0 +1,97 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{validate_key, ResponseOverrides, Storage};
+
+/// Path the files of the local storage are served from, see `controllers::storage`.
+pub const PUBLIC_PATH: &str = "/uploads/storage";
+
+/// Keeps objects as files under a root directory.
+pub struct LocalStorage {
+    root: PathBuf,
+    signing_secret: Vec<u8>,
+}
+
+impl LocalStorage {
+    pub fn new(root: &str, signing_secret: &str) -> Self {
+        Self {
+            root: PathBuf::from(root),
+            signing_secret: signing_secret.as_bytes().to_vec(),
+        }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+
+    fn mac(&self, key: &str, expires: i64, overrides: &ResponseOverrides) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.signing_secret).expect("HMAC accepts keys of any size");
+        mac.update(format!(
+            "{}\n{}\n{}\n{}",
+            key,
+            expires,
+            overrides.content_type.as_deref().unwrap_or_default(),
+            overrides.content_disposition.as_deref().unwrap_or_default()
+        ).as_bytes());
+        mac
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.context("Failed to create storage directory")?;
+        }
+        tokio::fs::write(&path, data).await.context("Failed to write file")?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).context("Failed to read file"),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err).context("Failed to delete file"),
+            _ => Ok(()),
+        }
+    }
+
+    async fn presigned_url(&self, key: &str, expires_in: Duration, overrides: &ResponseOverrides) -> Result<String> {
+        validate_key(key)?;
+        let expires = chrono::Utc::now().timestamp() + expires_in.as_secs() as i64;
+        let signature = hex::encode(self.mac(key, expires, overrides).finalize().into_bytes());
+
+        let mut url = format!("{}/{}?expires={}&signature={}", PUBLIC_PATH, key, expires, signature);
+        if let Some(content_type) = &overrides.content_type {
+            url.push_str(&format!("&content_type={}", urlencoding::encode(content_type)));
+        }
+        if let Some(content_disposition) = &overrides.content_disposition {
+            url.push_str(&format!("&content_disposition={}", urlencoding::encode(content_disposition)));
+        }
+        Ok(url)
+    }
+
+    fn verify_presigned_url(&self, key: &str, expires: i64, overrides: &ResponseOverrides, signature: &str) -> bool {
+        if expires < chrono::Utc::now().timestamp() {
+            return false;
+        }
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        self.mac(key, expires, overrides).verify_slice(&signature).is_ok()
+    }
+}
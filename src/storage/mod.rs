@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::config::StorageSettings;
+
+pub mod local;
+pub mod s3;
+
+/// Response headers a presigned URL makes the server send along with the object.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseOverrides {
+    pub content_type: Option<String>,
+    pub content_disposition: Option<String>,
+}
+
+/// Object storage for uploaded files. Keys are relative, `/`-separated paths.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<()>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+    async fn delete(&self, key: &str) -> Result<()>;
+    /// Returns a URL that grants access to the object without further authorization until it expires.
+    async fn presigned_url(&self, key: &str, expires_in: Duration, overrides: &ResponseOverrides) -> Result<String>;
+
+    /// Checks a URL issued by `presigned_url` for backends that serve the objects themselves.
+    fn verify_presigned_url(&self, _key: &str, _expires: i64, _overrides: &ResponseOverrides, _signature: &str) -> bool {
+        false
+    }
+}
+
+pub fn create_storage(settings: &StorageSettings, signing_secret: &str) -> Result<Arc<dyn Storage>> {
+    Ok(match settings {
+        StorageSettings::Local { root } => Arc::new(local::LocalStorage::new(root, signing_secret)),
+        StorageSettings::S3 {
+            bucket,
+            region,
+            endpoint,
+            access_key,
+            secret_key,
+            path_style,
+        } => Arc::new(s3::S3Storage::new(
+            bucket,
+            region,
+            endpoint.as_deref(),
+            access_key,
+            secret_key,
+            *path_style,
+        )?),
+    })
+}
+
+/// Rejects keys that could escape the storage root.
+pub fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.starts_with('/') || key.split('/').any(|part| part.is_empty() || part == "." || part == "..") {
+        anyhow::bail!("Invalid storage key: {}", key);
+    }
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ::s3::{creds::Credentials, Bucket, Region};
+
+use super::{validate_key, ResponseOverrides, Storage};
+
+/// Keeps objects in an S3-compatible bucket, such as AWS S3 or MinIO.
+pub struct S3Storage {
+    bucket: Box<Bucket>,
+}
+
+impl S3Storage {
+    pub fn new(
+        bucket: &str,
+        region: &str,
+        endpoint: Option<&str>,
+        access_key: &str,
+        secret_key: &str,
+        path_style: bool,
+    ) -> Result<Self> {
+        let region = match endpoint {
+            Some(endpoint) => Region::Custom {
+                region: region.to_string(),
+                endpoint: endpoint.to_string(),
+            },
+            None => region.parse().context("Invalid S3 region")?,
+        };
+        let credentials = Credentials::new(Some(access_key), Some(secret_key), None, None, None)
+            .context("Invalid S3 credentials")?;
+        let mut bucket = Bucket::new(bucket, region, credentials).context("Failed to create S3 bucket client")?;
+        // MinIO and most self-hosted services do not support virtual-hosted buckets
+        if path_style {
+            bucket = bucket.with_path_style();
+        }
+        Ok(Self { bucket })
+    }
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<()> {
+        validate_key(key)?;
+        let response = self.bucket
+            .put_object_with_content_type(key, &data, content_type)
+            .await
+            .context("Failed to upload object")?;
+        if response.status_code() >= 300 {
+            anyhow::bail!("Failed to upload object: status {}", response.status_code());
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        validate_key(key)?;
+        let response = self.bucket.get_object(key).await.context("Failed to download object")?;
+        match response.status_code() {
+            404 => Ok(None),
+            status if status >= 300 => anyhow::bail!("Failed to download object: status {}", status),
+            _ => Ok(Some(response.bytes().to_vec())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        validate_key(key)?;
+        let response = self.bucket.delete_object(key).await.context("Failed to delete object")?;
+        if response.status_code() >= 300 && response.status_code() != 404 {
+            anyhow::bail!("Failed to delete object: status {}", response.status_code());
+        }
+        Ok(())
+    }
+
+    async fn presigned_url(&self, key: &str, expires_in: Duration, overrides: &ResponseOverrides) -> Result<String> {
+        validate_key(key)?;
+        let mut queries = HashMap::new();
+        if let Some(content_type) = &overrides.content_type {
+            queries.insert("response-content-type".to_string(), content_type.clone());
+        }
+        if let Some(content_disposition) = &overrides.content_disposition {
+            queries.insert("response-content-disposition".to_string(), content_disposition.clone());
+        }
+        self.bucket
+            .presign_get(key, expires_in.as_secs() as u32, Some(queries))
+            .await
+            .context("Failed to presign object URL")
+    }
+}
@@ -1,6 +1,18 @@
-use crate::mailer::mailer::Mailer;
+use std::sync::Arc;
+
+use tokio::sync::{broadcast, Notify};
+
+use crate::{config::{AttachmentSettings, RepositorySettings}, github::providers::IssueProviders, mailer::mailer::Mailer, models::board::BoardEvent, storage::Storage};
 
 #[derive(Clone)]
 pub struct AppData {
     pub mailer: Mailer,
+    pub sync_trigger: Arc<Notify>,
+    pub issue_providers: IssueProviders,
+    pub attachments: AttachmentSettings,
+    pub repositories: RepositorySettings,
+    pub storage: Arc<dyn Storage>,
+    /// Task moves of every replica for the board viewers connected to this one,
+    /// fed by the `BoardEventListener`.
+    pub board_events: broadcast::Sender<BoardEvent>,
 }
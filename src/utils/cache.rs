@@ -0,0 +1,35 @@
+use actix_web::{
+    http::header::{self, CacheControl, CacheDirective, EntityTag, IfMatch, IfNoneMatch},
+    HttpRequest, HttpResponse, HttpResponseBuilder,
+};
+
+use crate::utils::response::ErrorResponse;
+
+/// Starts a response for a resource identified by `etag`. Returns a 304 builder when the
+/// client already has that version, otherwise a 200 one; both carry the caching headers.
+pub fn conditional_response(req: &HttpRequest, etag: EntityTag, max_age: u32) -> (HttpResponseBuilder, bool) {
+    let not_modified = match req.get_header::<IfNoneMatch>() {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        None => false,
+    };
+    let mut response = if not_modified { HttpResponse::NotModified() } else { HttpResponse::Ok() };
+    response
+        .insert_header(header::ETag(etag))
+        .insert_header(CacheControl(vec![CacheDirective::Public, CacheDirective::MaxAge(max_age)]));
+    (response, not_modified)
+}
+
+/// The version an update is based on, taken from the `If-Match` header. Without the header
+/// or with `*` the update applies to any version.
+pub fn if_match_version(req: &HttpRequest) -> Result<Option<u64>, ErrorResponse> {
+    let tags = match req.get_header::<IfMatch>() {
+        Some(IfMatch::Items(tags)) => tags,
+        Some(IfMatch::Any) | None => return Ok(None),
+    };
+    match tags.as_slice() {
+        [tag] => tag.tag().parse().map(Some)
+            .map_err(|_| ErrorResponse::BadRequest("If-Match must hold an ETag of the resource".to_string())),
+        _ => Err(ErrorResponse::BadRequest("If-Match must hold a single ETag".to_string())),
+    }
+}
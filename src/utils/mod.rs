@@ -1,3 +1,5 @@
 pub mod response;
 pub mod openapi;
 pub mod app_data;
+pub mod cache;
+pub mod secret;
@@ -1,8 +1,13 @@
 use std::fmt::Display;
-use actix_web::{http::StatusCode, ResponseError};
+use actix_web::{
+    body::BoxBody,
+    http::{header::{self, EntityTag}, StatusCode},
+    HttpRequest, HttpResponse, Responder, ResponseError,
+};
 use apistos::{ApiComponent, ApiErrorComponent};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
 #[openapi_error(
@@ -10,6 +15,7 @@ use serde::{Deserialize, Serialize};
     status(code = 405),
     status(code = 404),
     status(code = 401),
+    status(code = 409),
     status(code = 500),
 )]
 pub enum ErrorResponse {
@@ -17,9 +23,17 @@ pub enum ErrorResponse {
     MethodNotAllowed(String),
     NotFound(String),
     Unauthorized(String),
+    /// The resource changed since the version the client sent. Carries its current representation.
+    Conflict(String, Value),
     InternalServerError(String),
 }
 
+impl ErrorResponse {
+    pub fn conflict<T: Serialize>(message: String, current: &T) -> Self {
+        ErrorResponse::Conflict(message, serde_json::to_value(current).unwrap_or(Value::Null))
+    }
+}
+
 impl Display for ErrorResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let message = match self {
@@ -27,6 +41,9 @@ impl Display for ErrorResponse {
             ErrorResponse::MethodNotAllowed(message) => message,
             ErrorResponse::NotFound(message) => message,
             ErrorResponse::Unauthorized(message) => message,
+            ErrorResponse::Conflict(message, current) => {
+                return write!(f, r#"{{"status": "error", "message": "{}", "data": {}}}"#, message, current);
+            }
             ErrorResponse::InternalServerError(message) => message,
         };
         write!(f, r#"{{"status": "error", "message": "{}"}}"#, message)
@@ -40,6 +57,7 @@ impl ResponseError for ErrorResponse {
             ErrorResponse::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
             ErrorResponse::NotFound(_) => StatusCode::NOT_FOUND,
             ErrorResponse::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ErrorResponse::Conflict(_, _) => StatusCode::CONFLICT,
             ErrorResponse::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -59,3 +77,33 @@ impl<T: JsonSchema> SuccessResponse<T> {
         }
     }
 }
+
+/// A success response that sends the version of the resource as its `ETag`.
+/// Updates based on it pass the tag back in `If-Match`.
+#[derive(Serialize, Debug, Clone, JsonSchema, ApiComponent)]
+#[serde(transparent)]
+pub struct VersionedResponse<T: JsonSchema> {
+    body: SuccessResponse<T>,
+    #[serde(skip)]
+    #[schemars(skip)]
+    version: u64,
+}
+
+impl<T: JsonSchema> VersionedResponse<T> {
+    pub fn new(data: T, version: u64) -> Self {
+        Self {
+            body: SuccessResponse::new(data),
+            version,
+        }
+    }
+}
+
+impl<T: JsonSchema + Serialize> Responder for VersionedResponse<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok()
+            .insert_header(header::ETag(EntityTag::new_strong(self.version.to_string())))
+            .json(self.body)
+    }
+}
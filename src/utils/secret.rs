@@ -0,0 +1,50 @@
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+
+/// Marks values sealed by `encrypt_secret`.
+const SEALED_PREFIX: &'static str = "sealed:";
+
+/// Key for secrets stored in the database, derived once at startup from a passphrase.
+#[derive(Clone)]
+pub struct SecretKey([u8; 32]);
+
+impl SecretKey {
+    pub fn new(passphrase: &str) -> Self {
+        Self(Sha256::digest(passphrase.as_bytes()).into())
+    }
+
+    fn aead_key(&self) -> Result<LessSafeKey, String> {
+        UnboundKey::new(&AES_256_GCM, &self.0)
+            .map(LessSafeKey::new)
+            .map_err(|_| "Invalid secret key".to_string())
+    }
+}
+
+/// Encrypts a secret, like a repository access token, for storing it in the database.
+pub fn encrypt_secret(key: &SecretKey, secret: &str) -> Result<String, String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new().fill(&mut nonce).map_err(|_| "Failed to generate nonce".to_string())?;
+    let mut sealed = secret.as_bytes().to_vec();
+    key.aead_key()?
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut sealed)
+        .map_err(|_| "Failed to encrypt secret".to_string())?;
+    Ok(format!("{}{}{}", SEALED_PREFIX, hex::encode(nonce), hex::encode(sealed)))
+}
+
+pub fn decrypt_secret(key: &SecretKey, stored: &str) -> Result<String, String> {
+    let sealed = stored
+        .strip_prefix(SEALED_PREFIX)
+        .ok_or_else(|| "Secret is not encrypted".to_string())?;
+    let sealed = hex::decode(sealed).map_err(|_| "Malformed encrypted secret".to_string())?;
+    if sealed.len() < NONCE_LEN {
+        return Err("Malformed encrypted secret".to_string());
+    }
+    let (nonce, sealed) = sealed.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| "Malformed encrypted secret".to_string())?;
+    let mut sealed = sealed.to_vec();
+    let secret = key.aead_key()?
+        .open_in_place(nonce, Aad::empty(), &mut sealed)
+        .map_err(|_| "Failed to decrypt secret".to_string())?;
+    String::from_utf8(secret.to_vec()).map_err(|_| "Malformed encrypted secret".to_string())
+}